@@ -2,7 +2,15 @@ use crate::{
     io::{self, Read, Seek},
     BinRead, BinResult, Endian, Error, NamedArgs,
 };
-use alloc::{boxed::Box, vec::Vec};
+#[cfg(target_has_atomic = "ptr")]
+use alloc::sync::Arc;
+use alloc::{
+    borrow::{Cow, ToOwned},
+    boxed::Box,
+    collections::{BTreeMap, BTreeSet, VecDeque},
+    rc::Rc,
+    vec::Vec,
+};
 use core::num::{
     NonZeroI128, NonZeroI16, NonZeroI32, NonZeroI64, NonZeroI8, NonZeroU128, NonZeroU16,
     NonZeroU32, NonZeroU64, NonZeroU8,
@@ -35,10 +43,10 @@ macro_rules! binread_impl {
 
 binread_impl!(u8, u16, u32, u64, u128, i8, i16, i32, i64, i128, f32, f64);
 
-fn unexpected_zero_num() -> Error {
+fn unexpected_zero_num(type_name: &str) -> Error {
     Error::Io(io::Error::new(
         io::ErrorKind::InvalidData,
-        "unexpected zero found",
+        alloc::format!("unexpected zero found for {type_name}"),
     ))
 }
 
@@ -55,7 +63,7 @@ macro_rules! binread_nonzero_impl {
                 ) -> BinResult<Self> {
                     match <$Ty>::new(<$Int>::read_options(reader, endian, ())?) {
                         Some(x) => Ok(x),
-                        None => Err(unexpected_zero_num()),
+                        None => Err(unexpected_zero_num(stringify!($Ty))),
                     }
                 }
             }
@@ -68,7 +76,8 @@ binread_nonzero_impl! {
     NonZeroI8, i8, NonZeroI16, i16, NonZeroI32, i32, NonZeroI64, i64, NonZeroI128, i128,
 }
 
-/// Named arguments for the [`BinRead::read_options()`] implementation of [`Vec`].
+/// Named arguments for the [`BinRead::read_options()`] implementation of [`Vec`]
+/// and other collections.
 ///
 /// # Examples
 ///
@@ -144,6 +153,113 @@ where
     ) -> BinResult<Self> {
         crate::helpers::count_with(args.count, B::read_options)(reader, endian, args.inner)
     }
+
+    fn read_options_into<R: Read + Seek>(
+        reader: &mut R,
+        endian: Endian,
+        args: Self::Args<'_>,
+        dest: &mut Self,
+    ) -> BinResult<()> {
+        dest.clear();
+
+        if !crate::helpers::read_vec_fast(dest, reader, endian, args.count)? {
+            dest.reserve(args.count);
+            for _ in 0..args.count {
+                dest.push(B::read_options(reader, endian, args.inner.clone())?);
+            }
+        }
+        Ok(())
+    }
+}
+
+impl<B> BinRead for VecDeque<B>
+where
+    B: BinRead + 'static,
+    for<'a> B::Args<'a>: Clone,
+{
+    type Args<'a> = VecArgs<B::Args<'a>>;
+
+    fn read_options<R: Read + Seek>(
+        reader: &mut R,
+        endian: Endian,
+        args: Self::Args<'_>,
+    ) -> BinResult<Self> {
+        crate::helpers::count_with(args.count, B::read_options)(reader, endian, args.inner)
+    }
+}
+
+impl<B> BinRead for BTreeSet<B>
+where
+    B: BinRead + Ord + 'static,
+    for<'a> B::Args<'a>: Clone,
+{
+    type Args<'a> = VecArgs<B::Args<'a>>;
+
+    fn read_options<R: Read + Seek>(
+        reader: &mut R,
+        endian: Endian,
+        args: Self::Args<'_>,
+    ) -> BinResult<Self> {
+        crate::helpers::count_with(args.count, B::read_options)(reader, endian, args.inner)
+    }
+}
+
+/// If the same key is read more than once, the last value is kept.
+impl<K, V> BinRead for BTreeMap<K, V>
+where
+    K: Ord + 'static,
+    V: 'static,
+    (K, V): BinRead,
+    for<'a> <(K, V) as BinRead>::Args<'a>: Clone,
+{
+    type Args<'a> = VecArgs<<(K, V) as BinRead>::Args<'a>>;
+
+    fn read_options<R: Read + Seek>(
+        reader: &mut R,
+        endian: Endian,
+        args: Self::Args<'_>,
+    ) -> BinResult<Self> {
+        crate::helpers::count_with(args.count, <(K, V)>::read_options)(reader, endian, args.inner)
+    }
+}
+
+#[cfg(feature = "std")]
+impl<B, S> BinRead for std::collections::HashSet<B, S>
+where
+    B: BinRead + Eq + core::hash::Hash + 'static,
+    for<'a> B::Args<'a>: Clone,
+    S: core::hash::BuildHasher + Default + 'static,
+{
+    type Args<'a> = VecArgs<B::Args<'a>>;
+
+    fn read_options<R: Read + Seek>(
+        reader: &mut R,
+        endian: Endian,
+        args: Self::Args<'_>,
+    ) -> BinResult<Self> {
+        crate::helpers::count_with(args.count, B::read_options)(reader, endian, args.inner)
+    }
+}
+
+/// If the same key is read more than once, the last value is kept.
+#[cfg(feature = "std")]
+impl<K, V, S> BinRead for std::collections::HashMap<K, V, S>
+where
+    K: Eq + core::hash::Hash + 'static,
+    V: 'static,
+    (K, V): BinRead,
+    for<'a> <(K, V) as BinRead>::Args<'a>: Clone,
+    S: core::hash::BuildHasher + Default + 'static,
+{
+    type Args<'a> = VecArgs<<(K, V) as BinRead>::Args<'a>>;
+
+    fn read_options<R: Read + Seek>(
+        reader: &mut R,
+        endian: Endian,
+        args: Self::Args<'_>,
+    ) -> BinResult<Self> {
+        crate::helpers::count_with(args.count, <(K, V)>::read_options)(reader, endian, args.inner)
+    }
 }
 
 impl<B, const N: usize> BinRead for [B; N]
@@ -207,6 +323,57 @@ impl<T: BinRead> BinRead for Box<T> {
     ) -> BinResult<Self> {
         Ok(Box::new(T::read_options(reader, endian, args)?))
     }
+
+    fn read_options_into<R: Read + Seek>(
+        reader: &mut R,
+        endian: Endian,
+        args: Self::Args<'_>,
+        dest: &mut Self,
+    ) -> BinResult<()> {
+        T::read_options_into(reader, endian, args, dest)
+    }
+}
+
+impl<T: BinRead> BinRead for Rc<T> {
+    type Args<'a> = T::Args<'a>;
+
+    fn read_options<R: Read + Seek>(
+        reader: &mut R,
+        endian: Endian,
+        args: Self::Args<'_>,
+    ) -> BinResult<Self> {
+        Ok(Rc::new(T::read_options(reader, endian, args)?))
+    }
+}
+
+#[cfg(target_has_atomic = "ptr")]
+impl<T: BinRead> BinRead for Arc<T> {
+    type Args<'a> = T::Args<'a>;
+
+    fn read_options<R: Read + Seek>(
+        reader: &mut R,
+        endian: Endian,
+        args: Self::Args<'_>,
+    ) -> BinResult<Self> {
+        Ok(Arc::new(T::read_options(reader, endian, args)?))
+    }
+}
+
+/// Always reads into [`Cow::Owned`].
+impl<T> BinRead for Cow<'_, T>
+where
+    T: ToOwned + ?Sized,
+    T::Owned: BinRead,
+{
+    type Args<'a> = <T::Owned as BinRead>::Args<'a>;
+
+    fn read_options<R: Read + Seek>(
+        reader: &mut R,
+        endian: Endian,
+        args: Self::Args<'_>,
+    ) -> BinResult<Self> {
+        Ok(Cow::Owned(T::Owned::read_options(reader, endian, args)?))
+    }
 }
 
 impl<T: BinRead> BinRead for Option<T> {
@@ -221,10 +388,120 @@ impl<T: BinRead> BinRead for Option<T> {
     }
 }
 
-impl<T> BinRead for core::marker::PhantomData<T> {
+impl<T: ?Sized> BinRead for core::marker::PhantomData<T> {
     type Args<'a> = ();
 
     fn read_options<R: Read + Seek>(_: &mut R, _: Endian, (): Self::Args<'_>) -> BinResult<Self> {
         Ok(core::marker::PhantomData)
     }
 }
+
+/// IP addresses are always read in network byte order, regardless of the
+/// endianness.
+#[cfg(feature = "std")]
+impl BinRead for std::net::Ipv4Addr {
+    type Args<'a> = ();
+
+    fn read_options<R: Read + Seek>(
+        reader: &mut R,
+        endian: Endian,
+        (): Self::Args<'_>,
+    ) -> BinResult<Self> {
+        <[u8; 4]>::read_options(reader, endian, ()).map(Self::from)
+    }
+}
+
+/// IP addresses are always read in network byte order, regardless of the
+/// endianness.
+#[cfg(feature = "std")]
+impl BinRead for std::net::Ipv6Addr {
+    type Args<'a> = ();
+
+    fn read_options<R: Read + Seek>(
+        reader: &mut R,
+        endian: Endian,
+        (): Self::Args<'_>,
+    ) -> BinResult<Self> {
+        <[u8; 16]>::read_options(reader, endian, ()).map(Self::from)
+    }
+}
+
+/// Reads the address in network byte order, followed by a `u16` port in the
+/// given endianness.
+#[cfg(feature = "std")]
+impl BinRead for std::net::SocketAddrV4 {
+    type Args<'a> = ();
+
+    fn read_options<R: Read + Seek>(
+        reader: &mut R,
+        endian: Endian,
+        (): Self::Args<'_>,
+    ) -> BinResult<Self> {
+        let ip = std::net::Ipv4Addr::read_options(reader, endian, ())?;
+        let port = u16::read_options(reader, endian, ())?;
+        Ok(Self::new(ip, port))
+    }
+}
+
+/// Reads the address in network byte order, followed by a `u16` port in the
+/// given endianness. The flow info and scope ID are set to zero.
+#[cfg(feature = "std")]
+impl BinRead for std::net::SocketAddrV6 {
+    type Args<'a> = ();
+
+    fn read_options<R: Read + Seek>(
+        reader: &mut R,
+        endian: Endian,
+        (): Self::Args<'_>,
+    ) -> BinResult<Self> {
+        let ip = std::net::Ipv6Addr::read_options(reader, endian, ())?;
+        let port = u16::read_options(reader, endian, ())?;
+        Ok(Self::new(ip, port, 0, 0))
+    }
+}
+
+/// Named arguments for the [`BinRead::read_options()`] implementation of
+/// [`SocketAddr`](std::net::SocketAddr).
+///
+/// # Examples
+///
+/// ```
+/// use binrw::{BinRead, io::Cursor};
+/// use std::net::SocketAddr;
+///
+/// #[derive(BinRead)]
+/// #[br(big)]
+/// struct Peer {
+///     is_v6: u8,
+///     #[br(args { is_v6: is_v6 != 0 })]
+///     addr: SocketAddr,
+/// }
+///
+/// let peer = Peer::read(&mut Cursor::new(b"\0\x7f\0\0\x01\x1f\x90")).unwrap();
+/// assert_eq!(peer.addr, "127.0.0.1:8080".parse().unwrap());
+/// ```
+#[cfg(feature = "std")]
+#[derive(NamedArgs, Clone, Copy, Debug)]
+pub struct SocketAddrArgs {
+    /// Whether to read an IPv6 address instead of an IPv4 address.
+    pub is_v6: bool,
+}
+
+/// The address family is not part of the data, so it must be given with
+/// [`SocketAddrArgs`].
+#[cfg(feature = "std")]
+impl BinRead for std::net::SocketAddr {
+    type Args<'a> = SocketAddrArgs;
+
+    fn read_options<R: Read + Seek>(
+        reader: &mut R,
+        endian: Endian,
+        args: Self::Args<'_>,
+    ) -> BinResult<Self> {
+        Ok(if args.is_v6 {
+            Self::V6(std::net::SocketAddrV6::read_options(reader, endian, ())?)
+        } else {
+            Self::V4(std::net::SocketAddrV4::read_options(reader, endian, ())?)
+        })
+    }
+}
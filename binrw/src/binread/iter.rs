@@ -0,0 +1,98 @@
+use super::BinRead;
+use crate::{
+    io::{Read, Seek},
+    BinResult, Endian,
+};
+
+/// An iterator which lazily reads objects from a reader until the end of the
+/// input.
+///
+/// This `struct` is created by [`BinReaderExt::iter_type_args`] and the other
+/// `iter_*` methods of [`BinReaderExt`]. Each call to [`next()`] reads one
+/// object using a clone of the arguments which were given when the iterator
+/// was created.
+///
+/// Iteration ends when the reader is at the end of the input before an object
+/// starts. If reading an object fails, the error is returned and iteration
+/// ends. An object which is truncated by the end of the input is an error.
+///
+/// [`BinReaderExt`]: super::BinReaderExt
+/// [`BinReaderExt::iter_type_args`]: super::BinReaderExt::iter_type_args
+/// [`next()`]: Iterator::next
+///
+/// # Examples
+///
+/// ```
+/// # use binrw::{BinRead, BinReaderExt, io::Cursor};
+/// #[derive(BinRead)]
+/// struct Record {
+///     kind: u8,
+///     value: u16,
+/// }
+///
+/// let mut reader = Cursor::new(b"\x01\0\x02\x03\0\x04");
+/// let mut total = 0;
+/// for record in reader.iter_be::<Record>() {
+///     total += record.unwrap().value;
+/// }
+/// assert_eq!(total, 6);
+/// ```
+pub struct ReadIter<'reader, 'args, R, T>
+where
+    T: BinRead,
+{
+    reader: &'reader mut R,
+    endian: Endian,
+    args: T::Args<'args>,
+    done: bool,
+}
+
+impl<'reader, 'args, R, T> ReadIter<'reader, 'args, R, T>
+where
+    R: Read + Seek,
+    T: BinRead,
+{
+    pub(super) fn new(reader: &'reader mut R, endian: Endian, args: T::Args<'args>) -> Self {
+        Self {
+            reader,
+            endian,
+            args,
+            done: false,
+        }
+    }
+}
+
+impl<'args, R, T> Iterator for ReadIter<'_, 'args, R, T>
+where
+    R: Read + Seek,
+    T: BinRead,
+    T::Args<'args>: Clone,
+{
+    type Item = BinResult<T>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        if self.done {
+            return None;
+        }
+
+        let result = match crate::__private::not_eof(self.reader) {
+            Ok(false) => {
+                self.done = true;
+                return None;
+            }
+            Ok(true) => T::read_options(self.reader, self.endian, self.args.clone()),
+            Err(err) => Err(err),
+        };
+
+        self.done = result.is_err();
+        Some(result)
+    }
+}
+
+impl<'args, R, T> core::iter::FusedIterator for ReadIter<'_, 'args, R, T>
+where
+    R: Read + Seek,
+    T: BinRead,
+    T::Args<'args>: Clone,
+{
+}
@@ -1,12 +1,16 @@
 mod impls;
+mod iter;
 
 use crate::{
-    io::{Read, Seek},
-    BinResult, Endian,
     __private::Required,
+    io::{Read, Seek},
     meta::ReadEndian,
+    BinResult, Endian,
 };
+#[cfg(feature = "std")]
+pub use impls::SocketAddrArgs;
 pub use impls::VecArgs;
+pub use iter::ReadIter;
 
 /// The `BinRead` trait reads data from streams and converts it into objects.
 ///
@@ -160,6 +164,28 @@ pub trait BinRead: Sized {
         endian: Endian,
         args: Self::Args<'_>,
     ) -> BinResult<Self>;
+
+    /// Read `Self` from the reader into an existing object using the given
+    /// [`Endian`] and arguments.
+    ///
+    /// The default implementation reads a new object and replaces `dest` with
+    /// it. Types which own allocations, like [`Vec`](alloc::vec::Vec),
+    /// override this to reuse the existing allocations of `dest`.
+    ///
+    /// # Errors
+    ///
+    /// If reading fails, an [`Error`](crate::Error) variant will be returned
+    /// and the contents of `dest` are unspecified.
+    #[inline]
+    fn read_options_into<R: Read + Seek>(
+        reader: &mut R,
+        endian: Endian,
+        args: Self::Args<'_>,
+        dest: &mut Self,
+    ) -> BinResult<()> {
+        *dest = Self::read_options(reader, endian, args)?;
+        Ok(())
+    }
 }
 
 /// Extension methods for reading [`BinRead`] objects directly from a reader.
@@ -286,6 +312,114 @@ pub trait BinReaderExt: Read + Seek + Sized {
     {
         self.read_type_args(Endian::NATIVE, args)
     }
+
+    /// Returns an iterator which reads `T` from the reader with the given byte
+    /// order until the end of the input.
+    ///
+    /// See [`ReadIter`] for more information.
+    #[inline]
+    fn iter_type<'a, T>(&mut self, endian: Endian) -> ReadIter<'_, 'a, Self, T>
+    where
+        T: BinRead,
+        T::Args<'a>: Required + Clone,
+    {
+        self.iter_type_args(endian, T::Args::args())
+    }
+
+    /// Returns an iterator which reads `T` from the reader assuming big-endian
+    /// byte order until the end of the input.
+    ///
+    /// See [`ReadIter`] for more information.
+    #[inline]
+    fn iter_be<'a, T>(&mut self) -> ReadIter<'_, 'a, Self, T>
+    where
+        T: BinRead,
+        T::Args<'a>: Required + Clone,
+    {
+        self.iter_type(Endian::Big)
+    }
+
+    /// Returns an iterator which reads `T` from the reader assuming
+    /// little-endian byte order until the end of the input.
+    ///
+    /// See [`ReadIter`] for more information.
+    #[inline]
+    fn iter_le<'a, T>(&mut self) -> ReadIter<'_, 'a, Self, T>
+    where
+        T: BinRead,
+        T::Args<'a>: Required + Clone,
+    {
+        self.iter_type(Endian::Little)
+    }
+
+    /// Returns an iterator which reads `T` from the reader assuming
+    /// native-endian byte order until the end of the input.
+    ///
+    /// See [`ReadIter`] for more information.
+    #[inline]
+    fn iter_ne<'a, T>(&mut self) -> ReadIter<'_, 'a, Self, T>
+    where
+        T: BinRead,
+        T::Args<'a>: Required + Clone,
+    {
+        self.iter_type(Endian::NATIVE)
+    }
+
+    /// Returns an iterator which reads `T` from the reader with the given byte
+    /// order and arguments until the end of the input.
+    ///
+    /// The arguments are cloned for each object which is read. See
+    /// [`ReadIter`] for more information.
+    #[inline]
+    fn iter_type_args<'a, T>(
+        &mut self,
+        endian: Endian,
+        args: T::Args<'a>,
+    ) -> ReadIter<'_, 'a, Self, T>
+    where
+        T: BinRead,
+        T::Args<'a>: Clone,
+    {
+        ReadIter::new(self, endian, args)
+    }
+
+    /// Read `T` from the reader into an existing object with the given byte
+    /// order.
+    ///
+    /// See [`BinRead::read_options_into`] for more information.
+    ///
+    /// # Errors
+    ///
+    /// If reading fails, an [`Error`](crate::Error) variant will be returned.
+    #[inline]
+    fn read_type_into<'a, T>(&mut self, endian: Endian, dest: &mut T) -> BinResult<()>
+    where
+        T: BinRead,
+        T::Args<'a>: Required,
+    {
+        self.read_type_args_into(endian, T::Args::args(), dest)
+    }
+
+    /// Read `T` from the reader into an existing object with the given byte
+    /// order and arguments.
+    ///
+    /// See [`BinRead::read_options_into`] for more information.
+    ///
+    /// # Errors
+    ///
+    /// If reading fails, an [`Error`](crate::Error) variant will be returned.
+    #[inline]
+    fn read_type_args_into<T>(
+        &mut self,
+        endian: Endian,
+        args: T::Args<'_>,
+        dest: &mut T,
+    ) -> BinResult<()>
+    where
+        T: BinRead,
+    {
+        T::read_options_into(self, endian, args, dest)
+    }
 }
 
 impl<R: Read + Seek + Sized> BinReaderExt for R {}
@@ -2,7 +2,15 @@ use crate::{
     io::{Seek, Write},
     BinResult, BinWrite, Endian,
 };
-use alloc::{boxed::Box, vec::Vec};
+#[cfg(target_has_atomic = "ptr")]
+use alloc::sync::Arc;
+use alloc::{
+    borrow::{Cow, ToOwned},
+    boxed::Box,
+    collections::{BTreeMap, BTreeSet, VecDeque},
+    rc::Rc,
+    vec::Vec,
+};
 use core::{
     any::Any,
     marker::PhantomData,
@@ -146,6 +154,75 @@ where
     }
 }
 
+macro_rules! binwrite_seq_impl {
+    ($($(#[$meta:meta])* [$($generics:ident),*] $Ty:ty),+ $(,)?) => {$(
+        $(#[$meta])*
+        impl<T, $($generics),*> BinWrite for $Ty
+        where
+            T: BinWrite,
+            for<'a> T::Args<'a>: Clone,
+        {
+            type Args<'a> = T::Args<'a>;
+
+            fn write_options<W: Write + Seek>(
+                &self,
+                writer: &mut W,
+                endian: Endian,
+                args: Self::Args<'_>,
+            ) -> BinResult<()> {
+                for item in self {
+                    item.write_options(writer, endian, args.clone())?;
+                }
+
+                Ok(())
+            }
+        }
+    )+};
+}
+
+binwrite_seq_impl!(
+    [] VecDeque<T>,
+    [] BTreeSet<T>,
+    #[cfg(feature = "std")]
+    [S] std::collections::HashSet<T, S>,
+);
+
+macro_rules! binwrite_map_impl {
+    ($($(#[$meta:meta])* [$($generics:ident),*] $Ty:ty),+ $(,)?) => {$(
+        /// Each entry is written as a key followed by its value, in the
+        /// iteration order of the map.
+        $(#[$meta])*
+        impl<K, V, Args, $($generics),*> BinWrite for $Ty
+        where
+            K: for<'a> BinWrite<Args<'a> = Args>,
+            V: for<'a> BinWrite<Args<'a> = Args>,
+            Args: Clone,
+        {
+            type Args<'a> = Args;
+
+            fn write_options<W: Write + Seek>(
+                &self,
+                writer: &mut W,
+                endian: Endian,
+                args: Self::Args<'_>,
+            ) -> BinResult<()> {
+                for (key, value) in self {
+                    key.write_options(writer, endian, args.clone())?;
+                    value.write_options(writer, endian, args.clone())?;
+                }
+
+                Ok(())
+            }
+        }
+    )+};
+}
+
+binwrite_map_impl!(
+    [] BTreeMap<K, V>,
+    #[cfg(feature = "std")]
+    [S] std::collections::HashMap<K, V, S>,
+);
+
 impl<T: BinWrite + ?Sized> BinWrite for &T {
     type Args<'a> = T::Args<'a>;
 
@@ -178,6 +255,46 @@ impl<T: BinWrite + ?Sized + 'static> BinWrite for Box<T> {
     }
 }
 
+impl<T: BinWrite + ?Sized> BinWrite for Rc<T> {
+    type Args<'a> = T::Args<'a>;
+
+    fn write_options<W: Write + Seek>(
+        &self,
+        writer: &mut W,
+        endian: Endian,
+        args: Self::Args<'_>,
+    ) -> BinResult<()> {
+        (**self).write_options(writer, endian, args)
+    }
+}
+
+#[cfg(target_has_atomic = "ptr")]
+impl<T: BinWrite + ?Sized> BinWrite for Arc<T> {
+    type Args<'a> = T::Args<'a>;
+
+    fn write_options<W: Write + Seek>(
+        &self,
+        writer: &mut W,
+        endian: Endian,
+        args: Self::Args<'_>,
+    ) -> BinResult<()> {
+        (**self).write_options(writer, endian, args)
+    }
+}
+
+impl<T: BinWrite + ToOwned + ?Sized> BinWrite for Cow<'_, T> {
+    type Args<'a> = T::Args<'a>;
+
+    fn write_options<W: Write + Seek>(
+        &self,
+        writer: &mut W,
+        endian: Endian,
+        args: Self::Args<'_>,
+    ) -> BinResult<()> {
+        (**self).write_options(writer, endian, args)
+    }
+}
+
 impl<T: BinWrite> BinWrite for Option<T> {
     type Args<'a> = T::Args<'a>;
 
@@ -194,14 +311,14 @@ impl<T: BinWrite> BinWrite for Option<T> {
     }
 }
 
-impl<T: BinWrite> BinWrite for PhantomData<T> {
-    type Args<'a> = T::Args<'a>;
+impl<T: ?Sized> BinWrite for PhantomData<T> {
+    type Args<'a> = ();
 
     fn write_options<W: Write + Seek>(
         &self,
         _: &mut W,
         _: Endian,
-        _: Self::Args<'_>,
+        (): Self::Args<'_>,
     ) -> BinResult<()> {
         Ok(())
     }
@@ -257,3 +374,87 @@ binwrite_tuple_impl!(
     b1, b2, b3, b4, b5, b6, b7, b8, b9, b10, b11, b12, b13, b14, b15, b16, b17, b18, b19, b20, b21,
     b22, b23, b24, b25, b26, b27, b28, b29, b30, b31, b32
 );
+
+/// IP addresses are always written in network byte order, regardless of the
+/// endianness.
+#[cfg(feature = "std")]
+impl BinWrite for std::net::Ipv4Addr {
+    type Args<'a> = ();
+
+    fn write_options<W: Write + Seek>(
+        &self,
+        writer: &mut W,
+        _: Endian,
+        (): Self::Args<'_>,
+    ) -> BinResult<()> {
+        writer.write_all(&self.octets()).map_err(Into::into)
+    }
+}
+
+/// IP addresses are always written in network byte order, regardless of the
+/// endianness.
+#[cfg(feature = "std")]
+impl BinWrite for std::net::Ipv6Addr {
+    type Args<'a> = ();
+
+    fn write_options<W: Write + Seek>(
+        &self,
+        writer: &mut W,
+        _: Endian,
+        (): Self::Args<'_>,
+    ) -> BinResult<()> {
+        writer.write_all(&self.octets()).map_err(Into::into)
+    }
+}
+
+/// Writes the address in network byte order, followed by a `u16` port in the
+/// given endianness.
+#[cfg(feature = "std")]
+impl BinWrite for std::net::SocketAddrV4 {
+    type Args<'a> = ();
+
+    fn write_options<W: Write + Seek>(
+        &self,
+        writer: &mut W,
+        endian: Endian,
+        (): Self::Args<'_>,
+    ) -> BinResult<()> {
+        self.ip().write_options(writer, endian, ())?;
+        self.port().write_options(writer, endian, ())
+    }
+}
+
+/// Writes the address in network byte order, followed by a `u16` port in the
+/// given endianness. The flow info and scope ID are not written.
+#[cfg(feature = "std")]
+impl BinWrite for std::net::SocketAddrV6 {
+    type Args<'a> = ();
+
+    fn write_options<W: Write + Seek>(
+        &self,
+        writer: &mut W,
+        endian: Endian,
+        (): Self::Args<'_>,
+    ) -> BinResult<()> {
+        self.ip().write_options(writer, endian, ())?;
+        self.port().write_options(writer, endian, ())
+    }
+}
+
+/// The address family is not written, so it must be stored separately.
+#[cfg(feature = "std")]
+impl BinWrite for std::net::SocketAddr {
+    type Args<'a> = ();
+
+    fn write_options<W: Write + Seek>(
+        &self,
+        writer: &mut W,
+        endian: Endian,
+        (): Self::Args<'_>,
+    ) -> BinResult<()> {
+        match self {
+            Self::V4(addr) => addr.write_options(writer, endian, ()),
+            Self::V6(addr) => addr.write_options(writer, endian, ()),
+        }
+    }
+}
@@ -1,10 +1,12 @@
 mod impls;
+mod size;
 
 use crate::{
+    __private::Required,
     io::{Seek, Write},
     BinResult, Endian,
-    __private::Required,
 };
+pub use size::BinSize;
 
 /// The `BinWrite` trait serialises objects and writes them to streams.
 ///
@@ -0,0 +1,115 @@
+use super::BinWrite;
+use crate::{
+    __private::Required,
+    io::{Error, ErrorKind, Result, Seek, SeekFrom, Write},
+    BinResult, Endian,
+};
+
+/// Extension methods for computing the serialised size of a [`BinWrite`]
+/// object without writing it.
+///
+/// This trait is implemented for every type which implements `BinWrite`, so it
+/// does not need to be derived. The size is found by running the object’s
+/// [`write_options()`](BinWrite::write_options) implementation against a
+/// writer which discards its data, so every directive which affects writing
+/// (padding, alignment, counts, magic, conditions, variable-length fields, and
+/// so on) is taken into account exactly as it would be when writing.
+///
+/// The size is the number of bytes from the starting position to the end of
+/// the furthest write, which is the number of bytes the object would occupy in
+/// a new buffer.
+///
+/// # Examples
+///
+/// ```
+/// # use binrw::{binwrite, BinSize, Endian};
+/// #[binwrite]
+/// #[bw(magic = b"IMG")]
+/// struct Image {
+///     #[bw(calc = pixels.len() as u16)]
+///     len: u16,
+///     #[bw(align_after = 4)]
+///     pixels: Vec<u8>,
+/// }
+///
+/// let image = Image { pixels: vec![1, 2, 3] };
+/// assert_eq!(image.bin_size(Endian::Big).unwrap(), 8);
+/// ```
+pub trait BinSize: BinWrite {
+    /// Returns the number of bytes `Self` would occupy when written with the
+    /// given [`Endian`].
+    ///
+    /// # Errors
+    ///
+    /// If writing would fail, an [`Error`](crate::Error) variant will be
+    /// returned.
+    #[inline]
+    fn bin_size(&self, endian: Endian) -> BinResult<u64>
+    where
+        for<'a> Self::Args<'a>: Required,
+    {
+        self.bin_size_args(endian, Self::Args::args())
+    }
+
+    /// Returns the number of bytes `Self` would occupy when written with the
+    /// given [`Endian`] and arguments.
+    ///
+    /// # Errors
+    ///
+    /// If writing would fail, an [`Error`](crate::Error) variant will be
+    /// returned.
+    fn bin_size_args(&self, endian: Endian, args: Self::Args<'_>) -> BinResult<u64> {
+        let mut counter = SizeCounter::default();
+        self.write_options(&mut counter, endian, args)?;
+        Ok(counter.end)
+    }
+}
+
+impl<T: BinWrite + ?Sized> BinSize for T {}
+
+/// A writer which discards its data and records the end of the furthest
+/// write.
+#[derive(Default)]
+struct SizeCounter {
+    pos: u64,
+    end: u64,
+}
+
+impl Write for SizeCounter {
+    fn write(&mut self, buf: &[u8]) -> Result<usize> {
+        if !buf.is_empty() {
+            self.pos += buf.len() as u64;
+            self.end = self.end.max(self.pos);
+        }
+        Ok(buf.len())
+    }
+
+    fn flush(&mut self) -> Result<()> {
+        Ok(())
+    }
+}
+
+impl Seek for SizeCounter {
+    fn seek(&mut self, pos: SeekFrom) -> Result<u64> {
+        let (base_pos, offset) = match pos {
+            SeekFrom::Start(n) => (n, 0),
+            SeekFrom::End(n) => (self.end, n),
+            SeekFrom::Current(n) => (self.pos, n),
+        };
+        let new_pos = if offset >= 0 {
+            base_pos.checked_add(offset.unsigned_abs())
+        } else {
+            base_pos.checked_sub(offset.unsigned_abs())
+        };
+        match new_pos {
+            Some(n) => {
+                self.pos = n;
+                Ok(n)
+            }
+            None => Err(Error::new(
+                ErrorKind::InvalidInput,
+                "invalid seek to a negative or overflowing position",
+            )),
+        }
+    }
+}
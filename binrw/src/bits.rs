@@ -0,0 +1,283 @@
+//! Type definitions for reading and writing values that are not aligned to
+//! byte boundaries.
+//!
+//! Fields that are packed into sub-byte bit ranges can be read with a
+//! [`BitReader`] and written with a [`BitWriter`], usually from a
+//! [custom parser or writer](crate::docs::attribute#custom-parserswriters).
+//!
+//! # Examples
+//!
+//! ```
+//! use binrw::{prelude::*, bits::{BitOrder, BitReader, BitWriter}, io::Cursor};
+//!
+//! #[binrw::parser(reader)]
+//! fn read_version_len() -> BinResult<(u8, u16)> {
+//!     let mut bits = BitReader::new(reader, BitOrder::Msb0);
+//!     Ok((bits.read_bits(3)? as u8, bits.read_bits(13)? as u16))
+//! }
+//!
+//! #[binrw::writer(writer)]
+//! fn write_version_len(value: &(u8, u16)) -> BinResult<()> {
+//!     let mut bits = BitWriter::new(writer, BitOrder::Msb0);
+//!     bits.write_bits(value.0.into(), 3)?;
+//!     bits.write_bits(value.1.into(), 13)?;
+//!     bits.finish()
+//! }
+//!
+//! #[derive(BinRead, BinWrite)]
+//! # #[derive(Debug, PartialEq)]
+//! struct Header {
+//!     #[br(parse_with = read_version_len)]
+//!     #[bw(write_with = write_version_len)]
+//!     version_len: (u8, u16),
+//!     flags: u8,
+//! }
+//!
+//! let header = Header::read_be(&mut Cursor::new(b"\x40\x20\xff")).unwrap();
+//! assert_eq!(header, Header { version_len: (2, 32), flags: 0xff });
+//!
+//! let mut out = Cursor::new(Vec::new());
+//! header.write_be(&mut out).unwrap();
+//! assert_eq!(out.into_inner(), b"\x40\x20\xff");
+//! ```
+
+use crate::{
+    io::{Read, Seek, Write},
+    BinResult, Error,
+};
+
+/// The order in which bits are taken from each byte.
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub enum BitOrder {
+    /// Bits are taken starting from the most significant bit of each byte,
+    /// and values are stored with their most significant bit first.
+    ///
+    /// This is the order used by most network protocols and media formats.
+    Msb0,
+    /// Bits are taken starting from the least significant bit of each byte,
+    /// and values are stored with their least significant bit first.
+    ///
+    /// This is the order used by DEFLATE and many compression formats.
+    Lsb0,
+}
+
+/// A reader that reads values of any number of bits.
+///
+/// Bytes are read from the underlying reader one at a time as they are
+/// needed. Any bits left over in the current byte when the `BitReader` is
+/// dropped are discarded.
+#[derive(Debug)]
+pub struct BitReader<R> {
+    reader: R,
+    order: BitOrder,
+    byte: u8,
+    remaining: u32,
+}
+
+impl<R: Read> BitReader<R> {
+    /// Creates a new `BitReader` which reads bits from `reader` in the given
+    /// order.
+    pub fn new(reader: R, order: BitOrder) -> Self {
+        Self {
+            reader,
+            order,
+            byte: 0,
+            remaining: 0,
+        }
+    }
+
+    /// Reads `count` bits and returns them as the low bits of a [`u64`].
+    ///
+    /// # Errors
+    ///
+    /// If reading fails, an [`Error`](crate::Error) variant will be
+    /// returned.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `count` is greater than 64.
+    pub fn read_bits(&mut self, count: u32) -> BinResult<u64> {
+        assert!(count <= 64, "cannot read more than 64 bits at once");
+
+        let mut value = 0;
+        let mut filled = 0;
+        while filled < count {
+            if self.remaining == 0 {
+                let mut byte = [0];
+                self.reader.read_exact(&mut byte)?;
+                self.byte = byte[0];
+                self.remaining = 8;
+            }
+
+            let take = (count - filled).min(self.remaining);
+            let bits = match self.order {
+                BitOrder::Msb0 => u64::from(self.byte >> (self.remaining - take)),
+                BitOrder::Lsb0 => u64::from(self.byte >> (8 - self.remaining)),
+            } & low_mask(take);
+            value = match self.order {
+                BitOrder::Msb0 => (value << take) | bits,
+                BitOrder::Lsb0 => value | (bits << filled),
+            };
+            self.remaining -= take;
+            filled += take;
+        }
+
+        Ok(value)
+    }
+
+    /// Reads a single bit.
+    ///
+    /// # Errors
+    ///
+    /// If reading fails, an [`Error`](crate::Error) variant will be
+    /// returned.
+    pub fn read_bool(&mut self) -> BinResult<bool> {
+        self.read_bits(1).map(|bit| bit != 0)
+    }
+
+    /// Discards any unread bits in the current byte, so that the next read
+    /// starts on a byte boundary.
+    pub fn align(&mut self) {
+        self.remaining = 0;
+    }
+
+    /// Returns `true` if the next read will start on a byte boundary.
+    #[must_use]
+    pub fn is_aligned(&self) -> bool {
+        self.remaining == 0
+    }
+
+    /// Consumes the `BitReader`, returning the underlying reader. Any unread
+    /// bits in the current byte are discarded.
+    pub fn into_inner(self) -> R {
+        self.reader
+    }
+}
+
+/// A writer that writes values of any number of bits.
+///
+/// Bytes are written to the underlying writer as soon as they are full.
+/// [`finish()`](Self::finish) must be called to write a final partial byte.
+#[derive(Debug)]
+pub struct BitWriter<W> {
+    writer: W,
+    order: BitOrder,
+    byte: u8,
+    used: u32,
+}
+
+impl<W: Write + Seek> BitWriter<W> {
+    /// Creates a new `BitWriter` which writes bits to `writer` in the given
+    /// order.
+    pub fn new(writer: W, order: BitOrder) -> Self {
+        Self {
+            writer,
+            order,
+            byte: 0,
+            used: 0,
+        }
+    }
+
+    /// Writes the low `count` bits of `value`.
+    ///
+    /// # Errors
+    ///
+    /// If `value` does not fit in `count` bits, an [`Error::AssertFail`] will
+    /// be returned. If writing fails, an [`Error`] variant will be returned.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `count` is greater than 64.
+    pub fn write_bits(&mut self, value: u64, count: u32) -> BinResult<()> {
+        assert!(count <= 64, "cannot write more than 64 bits at once");
+
+        if value & !low_mask(count) != 0 {
+            return Err(Error::AssertFail {
+                pos: self.writer.stream_position()?,
+                message: alloc::format!("value {value} does not fit in {count} bits"),
+            });
+        }
+
+        let mut written = 0;
+        while written < count {
+            let free = 8 - self.used;
+            let take = (count - written).min(free);
+            let bits = match self.order {
+                BitOrder::Msb0 => value >> (count - written - take),
+                BitOrder::Lsb0 => value >> written,
+            } & low_mask(take);
+            // Lint: `bits` has at most eight bits
+            #[allow(clippy::cast_possible_truncation)]
+            let bits = bits as u8;
+            self.byte |= match self.order {
+                BitOrder::Msb0 => bits << (free - take),
+                BitOrder::Lsb0 => bits << self.used,
+            };
+            self.used += take;
+            written += take;
+
+            if self.used == 8 {
+                self.writer.write_all(&[self.byte])?;
+                self.byte = 0;
+                self.used = 0;
+            }
+        }
+
+        Ok(())
+    }
+
+    /// Writes a single bit.
+    ///
+    /// # Errors
+    ///
+    /// If writing fails, an [`Error`] variant will be returned.
+    pub fn write_bool(&mut self, value: bool) -> BinResult<()> {
+        self.write_bits(value.into(), 1)
+    }
+
+    /// Pads the current byte with zero bits and writes it, so that the next
+    /// write starts on a byte boundary. Does nothing if the writer is already
+    /// aligned.
+    ///
+    /// # Errors
+    ///
+    /// If writing fails, an [`Error`] variant will be returned.
+    pub fn align(&mut self) -> BinResult<()> {
+        if self.used != 0 {
+            self.writer.write_all(&[self.byte])?;
+            self.byte = 0;
+            self.used = 0;
+        }
+
+        Ok(())
+    }
+
+    /// Returns `true` if the next write will start on a byte boundary.
+    #[must_use]
+    pub fn is_aligned(&self) -> bool {
+        self.used == 0
+    }
+
+    /// Pads and writes any partial byte, then consumes the `BitWriter`.
+    ///
+    /// # Errors
+    ///
+    /// If writing fails, an [`Error`] variant will be returned.
+    pub fn finish(mut self) -> BinResult<()> {
+        self.align()
+    }
+
+    /// Pads and writes any partial byte, then returns the underlying writer.
+    ///
+    /// # Errors
+    ///
+    /// If writing fails, an [`Error`] variant will be returned.
+    pub fn into_inner(mut self) -> BinResult<W> {
+        self.align()?;
+        Ok(self.writer)
+    }
+}
+
+fn low_mask(bits: u32) -> u64 {
+    u64::MAX.checked_shr(64 - bits).unwrap_or(0)
+}
@@ -0,0 +1,98 @@
+//! Collection of non-fatal diagnostics emitted while parsing.
+//!
+//! Some problems in a file are worth reporting but should not stop a parse,
+//! like a reserved field with a non-zero value or a checksum which does not
+//! match. These can be reported with the
+//! [`warn`](crate::docs::attribute#warn) directive, or by calling [`warn()`]
+//! from a custom parser.
+//!
+//! Diagnostics are only recorded while inside a call to [`collect()`].
+//! Outside of it, reporting a diagnostic does nothing.
+//!
+//! # Examples
+//!
+//! ```
+//! # use binrw::{BinRead, BinReaderExt, diagnostics, io::Cursor};
+//! #[derive(BinRead)]
+//! struct Header {
+//!     version: u8,
+//!     #[br(warn(reserved == 0, "reserved is {}", reserved))]
+//!     reserved: u8,
+//! }
+//!
+//! let (header, warnings) = diagnostics::collect(|| {
+//!     Cursor::new(b"\x01\x07").read_le::<Header>()
+//! });
+//! assert_eq!(header.unwrap().version, 1);
+//! assert_eq!(warnings.len(), 1);
+//! assert_eq!(warnings[0].message, "reserved is 7");
+//! ```
+
+use core::{cell::RefCell, fmt};
+
+std::thread_local! {
+    static SINK: RefCell<Option<Vec<Diagnostic>>> = const { RefCell::new(None) };
+}
+
+/// A non-fatal problem found while parsing.
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct Diagnostic {
+    /// The byte position of the object which emitted the diagnostic.
+    pub pos: u64,
+    /// A description of the problem.
+    pub message: String,
+}
+
+impl fmt::Display for Diagnostic {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{} at 0x{:x}", self.message, self.pos)
+    }
+}
+
+/// Runs `f` and returns its result along with every diagnostic reported by
+/// the current thread while it was running.
+///
+/// Calls may be nested. Diagnostics are only returned from the innermost
+/// call.
+pub fn collect<T>(f: impl FnOnce() -> T) -> (T, Vec<Diagnostic>) {
+    /// Restores the outer sink even if `f` panics.
+    struct Guard(Option<Vec<Diagnostic>>);
+
+    impl Drop for Guard {
+        fn drop(&mut self) {
+            let outer = self.0.take();
+            SINK.with(|sink| *sink.borrow_mut() = outer);
+        }
+    }
+
+    let guard = Guard(SINK.with(|sink| sink.borrow_mut().replace(Vec::new())));
+    let value = f();
+    let diagnostics = SINK
+        .with(|sink| sink.borrow_mut().take())
+        .unwrap_or_default();
+    drop(guard);
+    (value, diagnostics)
+}
+
+/// Reports a diagnostic at the given position.
+///
+/// This does nothing unless called from inside [`collect()`].
+pub fn warn(pos: u64, message: impl Into<String>) {
+    SINK.with(|sink| {
+        if let Some(diagnostics) = &mut *sink.borrow_mut() {
+            diagnostics.push(Diagnostic {
+                pos,
+                message: message.into(),
+            });
+        }
+    });
+}
+
+/// Returns true if diagnostics are currently being collected.
+///
+/// This can be used to skip building expensive messages which would be
+/// discarded.
+#[must_use]
+pub fn is_collecting() -> bool {
+    SINK.with(|sink| sink.borrow().is_some())
+}
@@ -1,6 +1,28 @@
 //! Type definitions for byte order handling.
+//!
+//! This module also contains wrappers for primitive types, such as [`u32le`]
+//! and [`u16be`], which always use the same byte order. These are useful for
+//! structs that mix byte orders, since no per-field directives are needed:
+//!
+//! ```
+//! use binrw::{BinRead, endian::{u16be, u32le}, io::Cursor};
+//!
+//! #[derive(BinRead)]
+//! struct Header {
+//!     magic: u16be,
+//!     size: u32le,
+//! }
+//!
+//! let header = Header::read_be(&mut Cursor::new(b"\x12\x34\x08\0\0\0")).unwrap();
+//! assert_eq!(header.magic, u16be(0x1234));
+//! assert_eq!(u32::from(header.size), 8);
+//! ```
 
-use crate::BinResult;
+use crate::{
+    io::{Read, Seek, Write},
+    meta::{EndianKind, ReadEndian, WriteEndian},
+    BinRead, BinResult, BinWrite,
+};
 use alloc::boxed::Box;
 pub use Endian::{Big as BE, Little as LE};
 
@@ -35,7 +57,8 @@ impl Endian {
             REVERSE_BOM => Ok(Self::Big),
             _ => Err(crate::Error::BadMagic {
                 pos: u64::MAX,
-                found: Box::new("Invalid UTF-16 BOM"),
+                expected: Box::new([BOM.to_le_bytes(), REVERSE_BOM.to_le_bytes()]),
+                found: Box::new(bom),
             }),
         }
     }
@@ -62,3 +85,83 @@ impl core::fmt::Display for Endian {
 
 const BOM: u16 = 0xFEFF;
 const REVERSE_BOM: u16 = 0xFFFE;
+
+macro_rules! fixed_endian_impl {
+    ($([$($derive:ident),*] $Ty:ty => $Name:ident, $endian:ident, $doc:literal),* $(,)?) => {
+        $(
+            #[doc = concat!("A [`", stringify!($Ty), "`] that is always read and written in ", $doc, " byte order,")]
+            /// regardless of the endianness of the surrounding data.
+            #[allow(non_camel_case_types)]
+            #[derive(Clone, Copy, Debug, Default, PartialEq, PartialOrd $(, $derive)*)]
+            pub struct $Name(pub $Ty);
+
+            impl BinRead for $Name {
+                type Args<'a> = ();
+
+                fn read_options<R: Read + Seek>(
+                    reader: &mut R,
+                    _: Endian,
+                    (): Self::Args<'_>,
+                ) -> BinResult<Self> {
+                    <$Ty>::read_options(reader, Endian::$endian, ()).map(Self)
+                }
+            }
+
+            impl BinWrite for $Name {
+                type Args<'a> = ();
+
+                fn write_options<W: Write + Seek>(
+                    &self,
+                    writer: &mut W,
+                    _: Endian,
+                    (): Self::Args<'_>,
+                ) -> BinResult<()> {
+                    self.0.write_options(writer, Endian::$endian, ())
+                }
+            }
+
+            impl ReadEndian for $Name {
+                const ENDIAN: EndianKind = EndianKind::Endian(Endian::$endian);
+            }
+
+            impl WriteEndian for $Name {
+                const ENDIAN: EndianKind = EndianKind::Endian(Endian::$endian);
+            }
+
+            impl From<$Ty> for $Name {
+                fn from(value: $Ty) -> Self {
+                    Self(value)
+                }
+            }
+
+            impl From<$Name> for $Ty {
+                fn from(value: $Name) -> Self {
+                    value.0
+                }
+            }
+        )*
+    };
+}
+
+fixed_endian_impl!(
+    [Eq, Ord, Hash] u16 => u16le, Little, "little-endian",
+    [Eq, Ord, Hash] u16 => u16be, Big, "big-endian",
+    [Eq, Ord, Hash] u32 => u32le, Little, "little-endian",
+    [Eq, Ord, Hash] u32 => u32be, Big, "big-endian",
+    [Eq, Ord, Hash] u64 => u64le, Little, "little-endian",
+    [Eq, Ord, Hash] u64 => u64be, Big, "big-endian",
+    [Eq, Ord, Hash] u128 => u128le, Little, "little-endian",
+    [Eq, Ord, Hash] u128 => u128be, Big, "big-endian",
+    [Eq, Ord, Hash] i16 => i16le, Little, "little-endian",
+    [Eq, Ord, Hash] i16 => i16be, Big, "big-endian",
+    [Eq, Ord, Hash] i32 => i32le, Little, "little-endian",
+    [Eq, Ord, Hash] i32 => i32be, Big, "big-endian",
+    [Eq, Ord, Hash] i64 => i64le, Little, "little-endian",
+    [Eq, Ord, Hash] i64 => i64be, Big, "big-endian",
+    [Eq, Ord, Hash] i128 => i128le, Little, "little-endian",
+    [Eq, Ord, Hash] i128 => i128be, Big, "big-endian",
+    [] f32 => f32le, Little, "little-endian",
+    [] f32 => f32be, Big, "big-endian",
+    [] f64 => f64le, Little, "little-endian",
+    [] f64 => f64be, Big, "big-endian",
+);
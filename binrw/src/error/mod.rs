@@ -135,6 +135,14 @@ impl dyn CustomError {
     // must be expressed this way due to borrowck limitations
     #[allow(clippy::missing_panics_doc)]
     pub fn downcast<T: CustomError + 'static>(self: Box<Self>) -> Result<Box<T>, Box<Self>> {
+        #[cfg(feature = "std")]
+        if (*self).as_any().is::<StdError>() {
+            let StdError { err, as_error } = *self.as_box_any().downcast().unwrap();
+            return err
+                .downcast()
+                .map_err(|err| Box::new(StdError { err, as_error }) as _);
+        }
+
         if self.is::<T>() {
             Ok(self.as_box_any().downcast().unwrap())
         } else {
@@ -145,18 +153,71 @@ impl dyn CustomError {
     /// Returns some mutable reference to the boxed value if it is of type `T`, or
     /// `None` if it isn't.
     pub fn downcast_mut<T: CustomError + 'static>(&mut self) -> Option<&mut T> {
-        self.as_any_mut().downcast_mut()
+        let any = self.as_any_mut();
+
+        #[cfg(feature = "std")]
+        if any.is::<StdError>() {
+            return any
+                .downcast_mut::<StdError>()
+                .and_then(|std_error| std_error.err.downcast_mut());
+        }
+
+        any.downcast_mut()
     }
 
     /// Returns some reference to the boxed value if it is of type `T`, or
     /// `None` if it isn’t.
     pub fn downcast_ref<T: CustomError + 'static>(&self) -> Option<&T> {
+        #[cfg(feature = "std")]
+        if let Some(std_error) = self.as_any().downcast_ref::<StdError>() {
+            return std_error.err.downcast_ref();
+        }
+
         self.as_any().downcast_ref()
     }
 
     /// Returns `true` if the boxed type is the same as `T`.
     pub fn is<T: CustomError + 'static>(&self) -> bool {
-        core::any::TypeId::of::<T>() == self.as_any().type_id()
+        self.downcast_ref::<T>().is_some()
+    }
+
+    /// Returns the boxed value as a [`std::error::Error`], if it was stored
+    /// by [`Error::custom`] or is a `Box<dyn std::error::Error + Send + Sync>`.
+    #[cfg(feature = "std")]
+    fn as_std_error(&self) -> Option<&(dyn std::error::Error + 'static)> {
+        if let Some(std_error) = self.as_any().downcast_ref::<StdError>() {
+            Some((std_error.as_error)(&*std_error.err))
+        } else {
+            self.as_any()
+                .downcast_ref::<Box<dyn std::error::Error + Send + Sync>>()
+                .map(|err| &**err as _)
+        }
+    }
+}
+
+/// A custom error which was created from a [`std::error::Error`] by
+/// [`Error::custom`].
+///
+/// The error is stored as a `dyn CustomError` so it can still be downcast to
+/// its original type, along with a function which recovers it as a
+/// `dyn std::error::Error` for [`source()`](std::error::Error::source).
+#[cfg(feature = "std")]
+struct StdError {
+    err: Box<dyn CustomError>,
+    as_error: for<'a> fn(&'a (dyn CustomError + 'static)) -> &'a (dyn std::error::Error + 'static),
+}
+
+#[cfg(feature = "std")]
+impl fmt::Debug for StdError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        fmt::Debug::fmt(&self.err, f)
+    }
+}
+
+#[cfg(feature = "std")]
+impl fmt::Display for StdError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        fmt::Display::fmt(&self.err, f)
     }
 }
 
@@ -168,6 +229,10 @@ pub enum Error {
         /// The byte position of the unexpected magic in the reader.
         pos: u64,
 
+        /// The value which was expected. If more than one value was allowed,
+        /// this is a list of all of the allowed values.
+        expected: Box<dyn fmt::Debug + Send + Sync>,
+
         /// The value which was actually read.
         found: Box<dyn fmt::Debug + Send + Sync>,
     },
@@ -199,6 +264,13 @@ pub enum Error {
     /// instead of a string literal. Assertions that use string literals are
     /// represented by the [`AssertFail`] variant.
     ///
+    /// When the `std` feature is enabled, a custom error which was created
+    /// with [`Error::custom`], or which is stored as a
+    /// `Box<dyn std::error::Error + Send + Sync>`, is returned by
+    /// [`source()`](std::error::Error::source), so it can be inspected by
+    /// other error handling libraries. Errors from `assert` and `try_map`
+    /// directives which implement [`std::error::Error`] are created this way.
+    ///
     /// [`assert`]: crate::docs::attribute#assert
     /// [`AssertFail`]: Self::AssertFail
     Custom {
@@ -214,9 +286,11 @@ pub enum Error {
     /// data in the reader.
     ///
     /// This variant is used when the [`return_unexpected_error`] directive is
-    /// set on an enum.
+    /// set on an enum, or when no variant matches the magic of an enum whose
+    /// variants are [selected by magic].
     ///
     /// [`return_unexpected_error`]: crate::docs::attribute#enum-errors
+    /// [selected by magic]: crate::docs::attribute#enum-errors
     NoVariantMatch {
         /// The byte position of the unparsable data in the reader.
         pos: u64,
@@ -246,6 +320,29 @@ pub enum Error {
 }
 
 impl Error {
+    /// Creates a [`Custom`](Self::Custom) error from a
+    /// [`std::error::Error`].
+    ///
+    /// Unlike constructing the variant directly with a `Box::new(err)`, the
+    /// error is kept as a `std::error::Error`, so it is returned by
+    /// [`source()`](std::error::Error::source). It can still be retrieved
+    /// with [`custom_err`](Self::custom_err).
+    // Lint: Does not panic; `as_error` is only ever called with the `err`
+    // stored next to it, which is always an `E`
+    #[allow(clippy::missing_panics_doc)]
+    #[cfg(feature = "std")]
+    #[cfg_attr(all(doc, nightly), doc(cfg(feature = "std")))]
+    #[must_use]
+    pub fn custom<E: std::error::Error + Send + Sync + 'static>(pos: u64, err: E) -> Self {
+        Self::Custom {
+            pos,
+            err: Box::new(StdError {
+                err: Box::new(err),
+                as_error: |err| err.downcast_ref::<E>().unwrap(),
+            }),
+        }
+    }
+
     /// Returns the source error. For a Backtrace this is the error that caused it, for every
     /// other error this returns self
     #[must_use]
@@ -270,6 +367,27 @@ impl Error {
         }
     }
 
+    /// Returns the byte position in the stream where the [root
+    /// cause][`Self::root_cause`] of this error occurred, or `None` if the
+    /// position is unknown.
+    ///
+    /// The position is unknown for [`Error::Io`] errors.
+    ///
+    /// Only the start of the region is available. Errors do not record the
+    /// length of the data being parsed, since for most variants it is not
+    /// known where the error is created.
+    #[must_use]
+    pub fn pos(&self) -> Option<u64> {
+        match self.root_cause() {
+            Error::BadMagic { pos, .. }
+            | Error::AssertFail { pos, .. }
+            | Error::Custom { pos, .. }
+            | Error::NoVariantMatch { pos }
+            | Error::EnumErrors { pos, .. } => Some(*pos),
+            Error::Io(_) | Error::Backtrace(_) => None,
+        }
+    }
+
     /// Returns a reference to the boxed error object if this `Error` is a
     /// custom error of type `T`, or `None` if it isn’t.
     #[must_use]
@@ -291,7 +409,14 @@ impl From<io::Error> for Error {
 impl fmt::Display for Error {
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
         match self {
-            Self::BadMagic { pos, found } => write!(f, "bad magic at 0x{pos:x}: {found:?}"),
+            Self::BadMagic {
+                pos,
+                expected,
+                found,
+            } => write!(
+                f,
+                "bad magic at 0x{pos:x}: expected {expected:?}, found {found:?}"
+            ),
             Self::AssertFail { pos, message } => write!(f, "{message} at 0x{pos:x}"),
             Self::Io(err) => fmt::Display::fmt(err, f),
             Self::Custom { pos, err } => write!(f, "{err} at 0x{pos:x}"),
@@ -318,7 +443,16 @@ impl fmt::Debug for Error {
 }
 
 #[cfg(feature = "std")]
-impl std::error::Error for Error {}
+impl std::error::Error for Error {
+    fn source(&self) -> Option<&(dyn std::error::Error + 'static)> {
+        match self {
+            Self::Io(err) => Some(err),
+            Self::Custom { err, .. } => err.as_std_error(),
+            Self::Backtrace(backtrace) => Some(&*backtrace.error),
+            _ => None,
+        }
+    }
+}
 
 mod private {
     use core::fmt;
@@ -128,9 +128,11 @@
 
 use crate::NamedArgs;
 use crate::{
-    io::{Read, Seek, SeekFrom},
-    BinRead, BinResult, Endian,
+    io::{Read, Seek, SeekFrom, Write},
+    BinRead, BinResult, BinWrite, Endian, Error, WriteHole,
 };
+use alloc::{collections::BTreeMap, rc::Rc};
+use core::cell::RefCell;
 use core::num::{
     NonZeroI128, NonZeroI16, NonZeroI32, NonZeroI64, NonZeroI8, NonZeroU128, NonZeroU16,
     NonZeroU32, NonZeroU64, NonZeroU8,
@@ -167,6 +169,14 @@ pub type NonZeroFilePtr128<T> = FilePtr<NonZeroU128, T>;
 /// [offset](crate::docs::attribute#offset) directive can be used to adjust the
 /// offset before the pointed-to value is read.
 ///
+/// When writing, the `ptr` offset is written first, and then the value is
+/// written at the position it points to. The offset is not recalculated, so
+/// it must already point to a valid location; this means that a `FilePtr`
+/// which was read from a stream can be written back without changes.
+/// When deriving `BinWrite`, the [deferred](crate::docs::attribute#deferred)
+/// directive can be used instead to write the value after the containing
+/// object and calculate the offset automatically.
+///
 /// `FilePtr` is not efficient when reading offset tables; see the
 /// [module documentation](binrw::file_ptr) for more information.
 ///
@@ -193,6 +203,25 @@ pub type NonZeroFilePtr128<T> = FilePtr<NonZeroU128, T>;
 ///           [pointer]           [value]
 /// 00000000: 0000 0008 0000 0000 ff                   ............
 /// ```
+///
+/// Arguments for the pointed-to value are passed using the `inner` field of
+/// [`FilePtrArgs`]:
+///
+/// ```
+/// # use binrw::{prelude::*, io::Cursor, FilePtr};
+/// #[derive(BinRead)]
+/// #[br(import(scale: u8))]
+/// struct Scaled(#[br(map = |x: u8| x * scale)] u8);
+///
+/// #[derive(BinRead)]
+/// struct Test {
+///     #[br(args { inner: (3,) })]
+///     value: FilePtr<u8, Scaled>,
+/// }
+///
+/// let test: Test = Cursor::new(b"\x01\x02").read_be().unwrap();
+/// assert_eq!(test.value.0, 6);
+/// ```
 #[derive(Debug, Eq)]
 pub struct FilePtr<Ptr: IntoSeekFrom, T> {
     /// The raw offset to the value.
@@ -220,6 +249,111 @@ where
     }
 }
 
+impl<Ptr, Value> BinWrite for FilePtr<Ptr, Value>
+where
+    Ptr: for<'a> BinWrite<Args<'a> = ()> + IntoSeekFrom,
+    Value: BinWrite,
+{
+    type Args<'a> = FilePtrArgs<Value::Args<'a>>;
+
+    fn write_options<W: Write + Seek>(
+        &self,
+        writer: &mut W,
+        endian: Endian,
+        args: Self::Args<'_>,
+    ) -> BinResult<()> {
+        self.ptr.write_options(writer, endian, ())?;
+        let before = writer.stream_position()?;
+        seek_to_ptr(writer, self.ptr, args.offset, args.offset_map)?;
+        let result = self.value.write_options(writer, endian, args.inner);
+        writer.seek(SeekFrom::Start(before))?;
+        result
+    }
+}
+
+impl<Ptr, Value> FilePtr<Ptr, Value>
+where
+    Ptr: for<'a> BinWrite<Args<'a> = ()> + IntoSeekFrom + Default + TryFrom<u64>,
+    Value: BinWrite,
+{
+    /// Writes a placeholder offset instead of [`ptr`](Self::ptr), returning a
+    /// [`DeferredFilePtr`] which writes the pointed-to value and fills in the
+    /// real offset later.
+    ///
+    /// This is used by the [`deferred`](crate::docs::attribute#deferred)
+    /// directive to write pointed-to values after the object containing the
+    /// pointer.
+    ///
+    /// # Errors
+    ///
+    /// If writing fails, an [`Error`](crate::Error) variant will be returned.
+    pub fn write_deferred<'a, W: Write + Seek>(
+        &'a self,
+        writer: &mut W,
+        endian: Endian,
+        args: FilePtrArgs<Value::Args<'a>>,
+    ) -> BinResult<DeferredFilePtr<'a, Ptr, Value>> {
+        Ok(DeferredFilePtr {
+            hole: WriteHole::reserve(writer, endian, ())?,
+            value: &self.value,
+            endian,
+            args,
+        })
+    }
+}
+
+/// A [`FilePtr`] with a placeholder offset whose pointed-to value has not been
+/// written yet.
+///
+/// This is created by [`FilePtr::write_deferred`].
+pub struct DeferredFilePtr<'a, Ptr, Value: BinWrite> {
+    hole: WriteHole<Ptr>,
+    value: &'a Value,
+    endian: Endian,
+    args: FilePtrArgs<Value::Args<'a>>,
+}
+
+impl<Ptr, Value> DeferredFilePtr<'_, Ptr, Value>
+where
+    Ptr: for<'a> BinWrite<Args<'a> = ()> + IntoSeekFrom + Default + TryFrom<u64>,
+    Value: BinWrite,
+{
+    /// Writes the pointed-to value at the current position of the writer, then
+    /// fills in the placeholder offset so that it points to the value.
+    ///
+    /// # Errors
+    ///
+    /// If the current position cannot be stored in `Ptr` after applying the
+    /// `offset` and `offset_map` arguments, an
+    /// [`AssertFail`](crate::Error::AssertFail) error will be returned. If
+    /// writing fails, an [`Error`](crate::Error) variant will be returned.
+    pub fn finish<W: Write + Seek>(self, writer: &mut W) -> BinResult<()> {
+        let pos = writer.stream_position()?;
+        let ptr = pos
+            .checked_sub(self.args.offset)
+            .and_then(|ptr| Ptr::try_from(ptr).ok())
+            .filter(|ptr| ptr_target(*ptr, self.args.offset, self.args.offset_map) == Some(pos))
+            .ok_or_else(|| Error::AssertFail {
+                pos: self.hole.pos(),
+                message: alloc::format!(
+                    "pointed-to value at 0x{pos:x} cannot be reached from this pointer"
+                ),
+            })?;
+        self.value
+            .write_options(writer, self.endian, self.args.inner)?;
+        self.hole.fill(writer, &ptr, ())
+    }
+}
+
+impl<Ptr, Value: BinWrite> core::fmt::Debug for DeferredFilePtr<'_, Ptr, Value> {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        f.debug_struct("DeferredFilePtr")
+            .field("hole", &self.hole)
+            .field("endian", &self.endian)
+            .finish_non_exhaustive()
+    }
+}
+
 impl<Ptr, Value> FilePtr<Ptr, Value>
 where
     Ptr: IntoSeekFrom,
@@ -315,6 +449,66 @@ where
         }
     }
 
+    /// Reads an offset, then returns the pointed-to value from `cache` if a
+    /// value at the same position was already read through it with the same
+    /// byte order. Otherwise, seeks to and parses the pointed-to value using
+    /// the [`BinRead`] implementation for `Value` and stores it in `cache`.
+    ///
+    /// The arguments for `Value` are not used to look up cached values; see
+    /// [`FilePtrCache`].
+    ///
+    /// This avoids parsing and allocating the same value many times when a
+    /// file refers to it from many places, such as entries in a string table.
+    ///
+    /// # Errors
+    ///
+    /// If reading fails, an [`Error`](crate::Error) variant will be returned.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use binrw::{prelude::*, io::Cursor, NullString};
+    /// use binrw::file_ptr::FilePtrCache;
+    /// use binrw::FilePtr8;
+    /// use std::rc::Rc;
+    ///
+    /// #[derive(BinRead)]
+    /// #[br(import(names: &FilePtrCache<NullString>))]
+    /// struct Record {
+    ///     #[br(parse_with = FilePtr8::parse_cached, args { cache: names })]
+    ///     name: Rc<NullString>,
+    /// }
+    ///
+    /// #[binread]
+    /// struct Table {
+    ///     #[br(temp, calc = FilePtrCache::new())]
+    ///     names: FilePtrCache<NullString>,
+    ///     #[br(count = 3, args { inner: (&names,) })]
+    ///     records: Vec<Record>,
+    /// }
+    ///
+    /// let table = Table::read_le(&mut Cursor::new(b"\x03\x07\x03hi\0yo\0")).unwrap();
+    /// assert_eq!(table.records[0].name.to_string(), "hi");
+    /// assert!(Rc::ptr_eq(&table.records[0].name, &table.records[2].name));
+    /// ```
+    #[binrw::parser(reader, endian)]
+    pub fn parse_cached<Args>(args: CachedFilePtrArgs<'_, Value, Args>, ...) -> BinResult<Rc<Value>>
+    where
+        Ptr: for<'a> BinRead<Args<'a> = ()> + IntoSeekFrom,
+        Value: for<'a> BinRead<Args<'a> = Args>,
+    {
+        let ptr = Ptr::read_options(reader, endian, ())?;
+        let before = reader.stream_position()?;
+        let pos = seek_to_ptr(reader, ptr, args.offset, args.offset_map)?;
+        let value = match args.cache.get(pos, endian) {
+            Some(value) => Ok(value),
+            None => Value::read_options(reader, endian, args.inner)
+                .map(|value| args.cache.insert(pos, endian, value)),
+        };
+        reader.seek(SeekFrom::Start(before))?;
+        value
+    }
+
     /// Consumes this object, returning the pointed-to value.
     pub fn into_inner(self) -> Value {
         self.value
@@ -331,10 +525,8 @@ where
         R: Read + Seek,
         Parser: FnOnce(&mut R, Endian, Args) -> BinResult<Value>,
     {
-        let relative_to = args.offset;
         let before = reader.stream_position()?;
-        reader.seek(SeekFrom::Start(relative_to))?;
-        reader.seek(ptr.into_seek_from())?;
+        seek_to_ptr(reader, ptr, args.offset, args.offset_map)?;
         let value = parser(reader, endian, args.inner);
         reader.seek(SeekFrom::Start(before))?;
         value
@@ -410,6 +602,397 @@ where
     }
 }
 
+/// A type alias for [`NullableFilePtr`] with 8-bit offsets.
+pub type NullableFilePtr8<T> = NullableFilePtr<u8, T>;
+/// A type alias for [`NullableFilePtr`] with 16-bit offsets.
+pub type NullableFilePtr16<T> = NullableFilePtr<u16, T>;
+/// A type alias for [`NullableFilePtr`] with 32-bit offsets.
+pub type NullableFilePtr32<T> = NullableFilePtr<u32, T>;
+/// A type alias for [`NullableFilePtr`] with 64-bit offsets.
+pub type NullableFilePtr64<T> = NullableFilePtr<u64, T>;
+/// A type alias for [`NullableFilePtr`] with 128-bit offsets.
+pub type NullableFilePtr128<T> = NullableFilePtr<u128, T>;
+
+/// A file pointer where a zero offset means that there is no value.
+///
+/// This works like [`FilePtr`], except that when the raw offset is zero, no
+/// seek is performed and the pointed-to value is [`None`]. The
+/// [offset](crate::docs::attribute#offset) directive is only applied to
+/// non-zero offsets.
+///
+/// When writing, the `ptr` offset is written first, and then the value is
+/// written at the position it points to if it is [`Some`]. As with `FilePtr`,
+/// the offset is not recalculated. Writing returns an error if the offset is
+/// zero but the value is [`Some`], or if the offset is non-zero but the value
+/// is [`None`].
+///
+/// # Examples
+///
+/// ```
+/// # use binrw::{prelude::*, io::Cursor};
+/// use binrw::file_ptr::NullableFilePtr16;
+///
+/// #[derive(BinRead)]
+/// #[br(big)]
+/// struct Test {
+///     name: NullableFilePtr16<u8>,
+///     comment: NullableFilePtr16<u8>,
+/// }
+///
+/// let test = Test::read(&mut Cursor::new(b"\0\x04\0\0\xff")).unwrap();
+/// assert_eq!(*test.name, Some(0xff));
+/// assert_eq!(*test.comment, None);
+/// ```
+#[derive(Debug, Eq)]
+pub struct NullableFilePtr<Ptr: IntoSeekFrom, T> {
+    /// The raw offset to the value.
+    pub ptr: Ptr,
+
+    /// The pointed-to value, or [`None`] if the offset is zero.
+    pub value: Option<T>,
+}
+
+impl<Ptr, Value> BinRead for NullableFilePtr<Ptr, Value>
+where
+    Ptr: for<'a> BinRead<Args<'a> = ()> + IntoSeekFrom + Default + PartialEq,
+    Value: BinRead,
+{
+    type Args<'a> = FilePtrArgs<Value::Args<'a>>;
+
+    fn read_options<R: Read + Seek>(
+        reader: &mut R,
+        endian: Endian,
+        args: Self::Args<'_>,
+    ) -> BinResult<Self> {
+        let ptr = Ptr::read_options(reader, endian, ())?;
+        let value = if ptr == Ptr::default() {
+            None
+        } else {
+            Some(FilePtr::read_value(
+                ptr,
+                Value::read_options,
+                reader,
+                endian,
+                args,
+            )?)
+        };
+        Ok(Self { ptr, value })
+    }
+}
+
+impl<Ptr, Value> BinWrite for NullableFilePtr<Ptr, Value>
+where
+    Ptr: for<'a> BinWrite<Args<'a> = ()> + IntoSeekFrom + Default + PartialEq,
+    Value: BinWrite,
+{
+    type Args<'a> = FilePtrArgs<Value::Args<'a>>;
+
+    fn write_options<W: Write + Seek>(
+        &self,
+        writer: &mut W,
+        endian: Endian,
+        args: Self::Args<'_>,
+    ) -> BinResult<()> {
+        let is_null = self.ptr == Ptr::default();
+        if is_null == self.value.is_some() {
+            return Err(Error::AssertFail {
+                pos: writer.stream_position()?,
+                message: if is_null {
+                    "null file pointer has a value".into()
+                } else {
+                    "non-null file pointer has no value".into()
+                },
+            });
+        }
+
+        self.ptr.write_options(writer, endian, ())?;
+        if let Some(value) = &self.value {
+            let before = writer.stream_position()?;
+            seek_to_ptr(writer, self.ptr, args.offset, args.offset_map)?;
+            let result = value.write_options(writer, endian, args.inner);
+            writer.seek(SeekFrom::Start(before))?;
+            result?;
+        }
+        Ok(())
+    }
+}
+
+impl<Ptr, Value> NullableFilePtr<Ptr, Value>
+where
+    Ptr: IntoSeekFrom,
+{
+    /// Reads an offset, then seeks to and parses the pointed-to value using the
+    /// [`BinRead`] implementation for `Value` if the offset is not zero.
+    /// Returns the pointed-to value.
+    ///
+    /// # Errors
+    ///
+    /// If reading fails, an [`Error`](crate::Error) variant will be returned.
+    #[binrw::parser(reader, endian)]
+    pub fn parse<Args>(args: FilePtrArgs<Args>, ...) -> BinResult<Option<Value>>
+    where
+        Ptr: for<'a> BinRead<Args<'a> = ()> + IntoSeekFrom + Default + PartialEq,
+        Value: for<'a> BinRead<Args<'a> = Args>,
+    {
+        Self::read_options(reader, endian, args).map(Self::into_inner)
+    }
+
+    /// Consumes this object, returning the pointed-to value.
+    pub fn into_inner(self) -> Option<Value> {
+        self.value
+    }
+}
+
+impl<Ptr, Value> Deref for NullableFilePtr<Ptr, Value>
+where
+    Ptr: IntoSeekFrom,
+{
+    type Target = Option<Value>;
+
+    fn deref(&self) -> &Self::Target {
+        &self.value
+    }
+}
+
+impl<Ptr, Value> DerefMut for NullableFilePtr<Ptr, Value>
+where
+    Ptr: IntoSeekFrom,
+{
+    fn deref_mut(&mut self) -> &mut Self::Target {
+        &mut self.value
+    }
+}
+
+impl<Ptr, Value> PartialEq<NullableFilePtr<Ptr, Value>> for NullableFilePtr<Ptr, Value>
+where
+    Ptr: IntoSeekFrom,
+    Value: PartialEq,
+{
+    fn eq(&self, other: &Self) -> bool {
+        self.value == other.value
+    }
+}
+
+/// A cache of values read by [`FilePtr::parse_cached`], keyed by their
+/// absolute position in the stream and the byte order they were read with.
+///
+/// Values are shared using [`Rc`], so a value which is referenced many times
+/// is only parsed and stored once. A cache is usually created in a
+/// [temporary](crate::docs::attribute#temp) field and passed to the fields
+/// which need it using [arguments](crate::docs::attribute#arguments).
+///
+/// The arguments for the inner type are not part of the key. If two pointers
+/// to the same position read it with different arguments, the second one gets
+/// the value read by the first. Use a separate cache for each interpretation
+/// of the data.
+#[derive(Debug)]
+pub struct FilePtrCache<T> {
+    values: RefCell<BTreeMap<(u64, bool), Rc<T>>>,
+}
+
+impl<T> FilePtrCache<T> {
+    /// Creates a new, empty cache.
+    #[must_use]
+    pub fn new() -> Self {
+        Self {
+            values: RefCell::new(BTreeMap::new()),
+        }
+    }
+
+    /// Returns the number of values in the cache.
+    #[must_use]
+    pub fn len(&self) -> usize {
+        self.values.borrow().len()
+    }
+
+    /// Returns `true` if the cache contains no values.
+    #[must_use]
+    pub fn is_empty(&self) -> bool {
+        self.values.borrow().is_empty()
+    }
+
+    /// Removes all values from the cache.
+    pub fn clear(&self) {
+        self.values.borrow_mut().clear();
+    }
+
+    fn get(&self, pos: u64, endian: Endian) -> Option<Rc<T>> {
+        self.values
+            .borrow()
+            .get(&(pos, endian == Endian::Big))
+            .cloned()
+    }
+
+    fn insert(&self, pos: u64, endian: Endian, value: T) -> Rc<T> {
+        let value = Rc::new(value);
+        self.values
+            .borrow_mut()
+            .insert((pos, endian == Endian::Big), value.clone());
+        value
+    }
+}
+
+impl<T> Default for FilePtrCache<T> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+/// A file pointer which reads the pointed-to value only when it is requested.
+///
+/// Unlike [`FilePtr`], reading a `LazyFilePtr` only reads the offset. The
+/// pointed-to value is read from a caller-supplied reader the first time
+/// [`get()`](Self::get) or [`get_args()`](Self::get_args) is called, and then
+/// cached for later calls. This is useful for large files where only a few
+/// sections are needed.
+///
+/// The [offset](crate::docs::attribute#offset) directive is supported, but
+/// arguments for the pointed-to value must be given to
+/// [`get_args()`](Self::get_args) instead of to the pointer.
+///
+/// When writing, only the offset is written.
+///
+/// # Examples
+///
+/// ```
+/// # use binrw::{prelude::*, io::Cursor};
+/// use binrw::file_ptr::LazyFilePtr;
+///
+/// #[derive(BinRead)]
+/// #[br(big)]
+/// struct Test {
+///     header: LazyFilePtr<u16, u16>,
+///     body: LazyFilePtr<u16, u8>,
+/// }
+///
+/// let mut data = Cursor::new(b"\0\x04\0\x06\x12\x34\xff");
+/// let mut test = Test::read(&mut data).unwrap();
+/// assert!(test.body.cached().is_none());
+/// assert_eq!(*test.body.get(&mut data).unwrap(), 0xff);
+/// assert_eq!(test.body.cached(), Some(&0xff));
+/// ```
+#[derive(Debug)]
+pub struct LazyFilePtr<Ptr: IntoSeekFrom, Value> {
+    /// The raw offset to the value.
+    pub ptr: Ptr,
+
+    pos: u64,
+    endian: Endian,
+    value: Option<Value>,
+}
+
+impl<Ptr, Value> BinRead for LazyFilePtr<Ptr, Value>
+where
+    Ptr: for<'a> BinRead<Args<'a> = ()> + IntoSeekFrom,
+{
+    type Args<'a> = FilePtrArgs<()>;
+
+    fn read_options<R: Read + Seek>(
+        reader: &mut R,
+        endian: Endian,
+        args: Self::Args<'_>,
+    ) -> BinResult<Self> {
+        let ptr = Ptr::read_options(reader, endian, ())?;
+        let Some(pos) = ptr_target(ptr, args.offset, args.offset_map) else {
+            return Err(Error::AssertFail {
+                pos: reader.stream_position()?,
+                message: "file pointer offset out of range".into(),
+            });
+        };
+
+        Ok(Self {
+            ptr,
+            pos,
+            endian,
+            value: None,
+        })
+    }
+}
+
+impl<Ptr, Value> BinWrite for LazyFilePtr<Ptr, Value>
+where
+    Ptr: for<'a> BinWrite<Args<'a> = ()> + IntoSeekFrom,
+{
+    type Args<'a> = ();
+
+    fn write_options<W: Write + Seek>(
+        &self,
+        writer: &mut W,
+        endian: Endian,
+        (): Self::Args<'_>,
+    ) -> BinResult<()> {
+        self.ptr.write_options(writer, endian, ())
+    }
+}
+
+impl<Ptr, Value> LazyFilePtr<Ptr, Value>
+where
+    Ptr: IntoSeekFrom,
+    Value: BinRead,
+{
+    /// Returns the pointed-to value, reading it from `reader` if it has not
+    /// been read yet.
+    ///
+    /// The position of `reader` is restored after reading.
+    ///
+    /// # Errors
+    ///
+    /// If reading fails, an [`Error`](crate::Error) variant will be returned.
+    pub fn get<R: Read + Seek>(&mut self, reader: &mut R) -> BinResult<&Value>
+    where
+        for<'a> Value::Args<'a>: Default,
+    {
+        self.get_args(reader, <_>::default())
+    }
+
+    /// Returns the pointed-to value, reading it from `reader` with the given
+    /// arguments if it has not been read yet.
+    ///
+    /// The position of `reader` is restored after reading.
+    ///
+    /// # Errors
+    ///
+    /// If reading fails, an [`Error`](crate::Error) variant will be returned.
+    pub fn get_args<R: Read + Seek>(
+        &mut self,
+        reader: &mut R,
+        args: Value::Args<'_>,
+    ) -> BinResult<&Value> {
+        if let Some(value) = self.value.take() {
+            return Ok(self.value.insert(value));
+        }
+
+        let before = reader.stream_position()?;
+        reader.seek(SeekFrom::Start(self.pos))?;
+        let value = Value::read_options(reader, self.endian, args);
+        reader.seek(SeekFrom::Start(before))?;
+        Ok(self.value.insert(value?))
+    }
+}
+
+impl<Ptr, Value> LazyFilePtr<Ptr, Value>
+where
+    Ptr: IntoSeekFrom,
+{
+    /// The absolute position of the pointed-to value.
+    #[must_use]
+    pub fn pos(&self) -> u64 {
+        self.pos
+    }
+
+    /// Returns the pointed-to value if it has already been read.
+    #[must_use]
+    pub fn cached(&self) -> Option<&Value> {
+        self.value.as_ref()
+    }
+
+    /// Consumes this object, returning the pointed-to value if it has already
+    /// been read.
+    pub fn into_inner(self) -> Option<Value> {
+        self.value
+    }
+}
+
 /// Creates a parser that reads a collection of values from an iterator of
 /// file offsets using the [`BinRead`] implementation of `Value`.
 ///
@@ -547,6 +1130,31 @@ where
     }
 }
 
+/// Seeks to the position that `ptr` points to, after applying `offset_map` to
+/// it and adding it to `offset`. Returns the new position.
+fn seek_to_ptr<S: Seek, Ptr: IntoSeekFrom>(
+    stream: &mut S,
+    ptr: Ptr,
+    offset: u64,
+    offset_map: fn(i64) -> i64,
+) -> crate::io::Result<u64> {
+    stream.seek(SeekFrom::Start(offset))?;
+    stream.seek(match ptr.into_seek_from() {
+        SeekFrom::Current(offset) => SeekFrom::Current(offset_map(offset)),
+        seek => seek,
+    })
+}
+
+/// Returns the position that `ptr` points to, after applying `offset_map` to it
+/// and adding it to `offset`, or `None` if the position is not representable.
+fn ptr_target<Ptr: IntoSeekFrom>(ptr: Ptr, offset: u64, offset_map: fn(i64) -> i64) -> Option<u64> {
+    match ptr.into_seek_from() {
+        SeekFrom::Start(pos) => Some(pos),
+        SeekFrom::Current(pos) => offset.checked_add_signed(offset_map(pos)),
+        SeekFrom::End(_) => None,
+    }
+}
+
 /// A trait to convert from an integer into [`SeekFrom::Current`].
 pub trait IntoSeekFrom: Copy {
     /// Converts the value.
@@ -592,17 +1200,62 @@ impl_into_seek_from_for_non_zero!(
     NonZeroU8
 );
 
-/// Named arguments for the [`BinRead::read_options()`] implementation of [`FilePtr`].
+/// Named arguments for the [`BinRead::read_options()`] and
+/// [`BinWrite::write_options()`] implementations of [`FilePtr`].
 ///
 /// The `inner` field can be omitted completely if the inner type doesn’t
 /// require arguments, in which case a default value will be used.
-#[derive(Clone, Default, NamedArgs)]
+///
+/// The builder and [`args!`](crate::args) fill in `offset` and `offset_map`
+/// when they are omitted. A struct literal must set every field; use
+/// `offset_map: core::convert::identity` to leave offsets unchanged.
+#[derive(Clone, NamedArgs)]
 pub struct FilePtrArgs<Inner> {
+    /// An absolute offset added to the [`FilePtr::ptr`](crate::FilePtr::ptr)
+    /// offset before reading or writing the pointed-to value.
+    #[named_args(default = 0)]
+    pub offset: u64,
+
+    /// A function which converts the [`FilePtr::ptr`](crate::FilePtr::ptr)
+    /// offset into a number of bytes, for formats which store offsets in
+    /// larger units.
+    #[named_args(default = core::convert::identity)]
+    pub offset_map: fn(i64) -> i64,
+
+    /// The [arguments](crate::BinRead::Args) for the inner type.
+    #[named_args(try_optional)]
+    pub inner: Inner,
+}
+
+impl<Inner: Default> Default for FilePtrArgs<Inner> {
+    fn default() -> Self {
+        Self {
+            offset: 0,
+            offset_map: core::convert::identity,
+            inner: Inner::default(),
+        }
+    }
+}
+
+/// Named arguments for [`FilePtr::parse_cached`].
+///
+/// The `inner` field can be omitted completely if the inner type doesn’t
+/// require arguments, in which case a default value will be used.
+#[derive(NamedArgs)]
+pub struct CachedFilePtrArgs<'cache, Value, Inner> {
+    /// The cache to read values from and store new values in.
+    pub cache: &'cache FilePtrCache<Value>,
+
     /// An absolute offset added to the [`FilePtr::ptr`](crate::FilePtr::ptr)
     /// offset before reading the pointed-to value.
     #[named_args(default = 0)]
     pub offset: u64,
 
+    /// A function which converts the [`FilePtr::ptr`](crate::FilePtr::ptr)
+    /// offset into a number of bytes.
+    #[named_args(default = core::convert::identity)]
+    pub offset_map: fn(i64) -> i64,
+
     /// The [arguments](crate::BinRead::Args) for the inner type.
     #[named_args(try_optional)]
     pub inner: Inner,
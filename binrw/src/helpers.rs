@@ -1,8 +1,8 @@
 //! Helper functions for reading and writing data.
 
 use crate::{
-    io::{self, Read, Seek},
-    BinRead, BinResult, Endian, Error,
+    io::{self, Read, Seek, Write},
+    BinRead, BinResult, BinWrite, Endian, Error,
 };
 use alloc::vec::Vec;
 use core::iter::from_fn;
@@ -275,6 +275,39 @@ where
     }
 }
 
+/// Reads all remaining bytes until the end of the input stream.
+///
+/// This is faster than using [`until_eof`] to read a `Vec<u8>`, since the
+/// data is read in large chunks instead of one byte at a time. The
+/// [`rest`](crate::docs::attribute#rest) directive is shorthand for using this
+/// function as a parser.
+///
+/// # Errors
+///
+/// If reading fails, an [`Io`](Error::Io) error will be returned.
+///
+/// # Examples
+///
+/// ```
+/// # use binrw::{BinRead, helpers::read_to_end, io::Cursor, BinReaderExt};
+/// #[derive(BinRead)]
+/// struct Packet {
+///     kind: u8,
+///     #[br(parse_with = read_to_end)]
+///     body: Vec<u8>,
+/// }
+///
+/// # let mut x = Cursor::new(b"\x01\x02\x03\x04");
+/// # let x: Packet = x.read_be().unwrap();
+/// # assert_eq!(x.body, &[2, 3, 4]);
+/// ```
+#[binrw::parser(reader)]
+pub fn read_to_end() -> BinResult<Vec<u8>> {
+    let mut data = Vec::new();
+    reader.read_to_end(&mut data)?;
+    Ok(data)
+}
+
 /// Creates a parser that builds a collection using items from the given
 /// iterable object as arguments for the parser.
 ///
@@ -451,34 +484,171 @@ where
     move |reader, endian, args| {
         let mut container = core::iter::empty::<T>().collect::<Ret>();
 
-        vec_fast_int!(try (i8 i16 u16 i32 u32 i64 u64 i128 u128) using (container, reader, endian, n) else {
-            // This extra branch for `Vec<u8>` makes it faster than
-            // `vec_fast_int`, but *only* because `vec_fast_int` is not allowed
-            // to use unsafe code to eliminate the unnecessary zero-fill.
-            // Otherwise, performance would be identical and it could be
-            // deleted.
-            if let Some(bytes) = <dyn core::any::Any>::downcast_mut::<Vec<u8>>(&mut container) {
-                bytes.reserve_exact(n);
-                let byte_count = reader
-                    .take(n.try_into().map_err(not_enough_bytes)?)
-                    .read_to_end(bytes)?;
-
-                if byte_count == n {
-                    Ok(container)
-                } else {
-                    Err(not_enough_bytes(()))
-                }
-            } else {
-                core::iter::repeat_with(|| read(reader, endian, args.clone()))
+        if read_vec_fast(&mut container, reader, endian, n)? {
+            Ok(container)
+        } else {
+            core::iter::repeat_with(|| read(reader, endian, args.clone()))
                 .take(n)
                 .collect()
+        }
+    }
+}
+
+/// Creates a parser that reads items into a collection, where each item
+/// starts `stride` bytes after the start of the previous item.
+///
+/// This is used for arrays where each element is padded to a fixed size. After
+/// each item is read, the reader skips to the start of the next item. The
+/// [`stride`](crate::docs::attribute#stride) directive is shorthand for using
+/// this function as a parser.
+///
+/// # Errors
+///
+/// If reading fails, an [`Error`] variant will be returned. If an item is
+/// larger than `stride`, an [`AssertFail`](Error::AssertFail) error will be
+/// returned.
+///
+/// # Examples
+///
+/// ```
+/// # use binrw::{BinRead, helpers::stride, io::Cursor, BinReaderExt};
+/// #[derive(BinRead)]
+/// struct Table {
+///     len: u8,
+///     #[br(parse_with = stride(4), args { count: len.into() })]
+///     entries: Vec<u16>,
+/// }
+///
+/// # let mut x = Cursor::new(b"\x02\0\x01\xff\xff\0\x02\xff\xff");
+/// # let x: Table = x.read_be().unwrap();
+/// # assert_eq!(x.entries, &[1, 2]);
+/// ```
+pub fn stride<R, T, Arg, Ret>(
+    stride: u64,
+) -> impl Fn(&mut R, Endian, crate::VecArgs<Arg>) -> BinResult<Ret>
+where
+    T: for<'a> BinRead<Args<'a> = Arg>,
+    R: Read + Seek,
+    Arg: Clone,
+    Ret: FromIterator<T>,
+{
+    move |reader, endian, args| {
+        core::iter::repeat_with(|| {
+            let start = reader.stream_position()?;
+            let item = T::read_options(reader, endian, args.inner.clone())?;
+            let pos = reader.stream_position()?;
+            let pad = stride_padding(pos, start, stride)?;
+            if pad != 0 {
+                reader.seek(io::SeekFrom::Start(pos + pad))?;
             }
+            Ok(item)
         })
+        .take(args.count)
+        .collect()
+    }
+}
+
+/// Creates a writer that writes items from a collection, padding each item
+/// with zeroes to `stride` bytes.
+///
+/// This is the inverse of [`stride`]. The
+/// [`stride`](crate::docs::attribute#stride) directive is shorthand for using
+/// this function as a writer.
+///
+/// # Errors
+///
+/// If writing fails, an [`Error`] variant will be returned. If an item is
+/// larger than `stride`, an [`AssertFail`](Error::AssertFail) error will be
+/// returned.
+///
+/// # Examples
+///
+/// ```
+/// # use binrw::{BinWrite, helpers::write_stride, io::Cursor};
+/// #[derive(BinWrite)]
+/// struct Table {
+///     #[bw(write_with = write_stride(4))]
+///     entries: Vec<u16>,
+/// }
+///
+/// # let mut x = Cursor::new(Vec::new());
+/// # Table { entries: vec![1, 2] }.write_be(&mut x).unwrap();
+/// # assert_eq!(x.into_inner(), b"\0\x01\0\0\0\x02\0\0");
+/// ```
+pub fn write_stride<W, C, T, Arg>(stride: u64) -> impl Fn(&C, &mut W, Endian, Arg) -> BinResult<()>
+where
+    W: Write + Seek,
+    C: ?Sized,
+    for<'a> &'a C: IntoIterator<Item = &'a T>,
+    T: for<'a> BinWrite<Args<'a> = Arg> + 'static,
+    Arg: Clone,
+{
+    move |value, writer, endian, args| {
+        for item in value {
+            let start = writer.stream_position()?;
+            item.write_options(writer, endian, args.clone())?;
+            let pad = stride_padding(writer.stream_position()?, start, stride)?;
+            crate::__private::write_zeroes(writer, pad)?;
+        }
+        Ok(())
     }
 }
 
+/// Returns the number of bytes from `pos` to the end of an item which starts
+/// at `start` and is `stride` bytes long.
+fn stride_padding(pos: u64, start: u64, stride: u64) -> BinResult<u64> {
+    let end = start.checked_add(stride).ok_or_else(|| Error::AssertFail {
+        pos: start,
+        message: alloc::format!("stride {stride} at position {start} overflows"),
+    })?;
+    end.checked_sub(pos).ok_or_else(|| Error::AssertFail {
+        pos: start,
+        message: alloc::format!("item size {} is larger than stride {stride}", pos - start),
+    })
+}
+
+/// Returns the current position of the reader without consuming any data.
+///
+/// This is useful for recording the absolute position of part of an object so
+/// that it can be used by later directives, such as to calculate the target of
+/// an offset that is relative to the start of a data section.
+///
+/// # Errors
+///
+/// If the position of the reader cannot be retrieved, an
+/// [`Error`] variant will be returned.
+///
+/// # Examples
+///
+/// ```
+/// # use binrw::{prelude::*, io::Cursor};
+/// use binrw::helpers::current_position;
+///
+/// #[derive(BinRead)]
+/// # #[derive(Debug, PartialEq)]
+/// struct Test {
+///     flags: u8,
+///     #[br(parse_with = current_position)]
+///     data_start: u64,
+///     data: u16,
+/// }
+/// #
+/// # assert_eq!(
+/// #     Test::read_be(&mut Cursor::new(b"\x01\x02\x03")).unwrap(),
+/// #     Test { flags: 1, data_start: 1, data: 0x203 }
+/// # );
+/// ```
+#[binrw::parser(reader)]
+pub fn current_position() -> binrw::BinResult<u64> {
+    reader.stream_position().map_err(Into::into)
+}
+
 /// Reads a 24-bit unsigned integer.
 ///
+/// # Errors
+///
+/// If reading fails, an [`Error`] variant will be returned.
+///
 /// # Examples
 ///
 /// ```
@@ -502,18 +672,18 @@ where
 /// ```
 #[binrw::parser(reader, endian)]
 pub fn read_u24() -> binrw::BinResult<u32> {
-    type ConvFn = fn([u8; 4]) -> u32;
-    let mut buf = [0u8; 4];
-    let (conv, out): (ConvFn, &mut [u8]) = match endian {
-        Endian::Little => (u32::from_le_bytes, &mut buf[..3]),
-        Endian::Big => (u32::from_be_bytes, &mut buf[1..]),
-    };
-    reader.read_exact(out)?;
-    Ok(conv(buf))
+    // Lint: A 24-bit value always fits
+    #[allow(clippy::cast_possible_truncation)]
+    read_uint::<_, 3>(reader, endian).map(|value| value as u32)
 }
 
 /// Writes a 24-bit unsigned integer.
 ///
+/// # Errors
+///
+/// If the value is too large to fit in 24 bits, an
+/// [`Error::AssertFail`] will be returned.
+///
 /// # Examples
 ///
 /// ```
@@ -541,11 +711,800 @@ pub fn read_u24() -> binrw::BinResult<u32> {
 /// ```
 #[binrw::writer(writer, endian)]
 pub fn write_u24(value: &u32) -> binrw::BinResult<()> {
-    let (buf, range) = match endian {
-        Endian::Little => (value.to_le_bytes(), 0..3),
-        Endian::Big => (value.to_be_bytes(), 1..4),
+    if *value > 0xff_ffff {
+        return int_out_of_range(writer, value, 24);
+    }
+    write_uint::<_, 3>(writer, endian, (*value).into())
+}
+
+/// Reads a 24-bit signed integer.
+///
+/// # Errors
+///
+/// If reading fails, an [`Error`] variant will be returned.
+///
+/// # Examples
+///
+/// ```
+/// # use binrw::{prelude::*, io::Cursor};
+/// #[derive(BinRead)]
+/// # #[derive(Debug, PartialEq)]
+/// struct Test {
+///     #[br(parse_with = binrw::helpers::read_i24)]
+///     value: i32,
+/// }
+/// #
+/// # assert_eq!(
+/// #     Test::read_be(&mut Cursor::new(b"\xff\xff\xfe")).unwrap(),
+/// #     Test { value: -2 }
+/// # );
+/// # assert_eq!(
+/// #     Test::read_le(&mut Cursor::new(b"\x04\x03\x02")).unwrap(),
+/// #     Test { value: 0x20304 }
+/// # );
+/// ```
+#[binrw::parser(reader, endian)]
+pub fn read_i24() -> binrw::BinResult<i32> {
+    // Lint: A sign-extended 24-bit value always fits
+    #[allow(clippy::cast_possible_truncation)]
+    read_uint::<_, 3>(reader, endian).map(|value| sign_extend(value, 24) as i32)
+}
+
+/// Writes a 24-bit signed integer.
+///
+/// # Errors
+///
+/// If the value is out of range of a 24-bit signed integer, an
+/// [`Error::AssertFail`] will be returned.
+///
+/// # Examples
+///
+/// ```
+/// # use binrw::{prelude::*, io::Cursor};
+/// #[derive(BinWrite)]
+/// struct Test {
+///     #[bw(write_with = binrw::helpers::write_i24)]
+///     value: i32,
+/// }
+/// #
+/// # let mut data = Cursor::new(vec![]);
+/// # Test { value: -2 }.write_be(&mut data).unwrap();
+/// # assert_eq!(data.get_ref(), &[0xff, 0xff, 0xfe]);
+/// # let mut data = Cursor::new(vec![]);
+/// # assert!(Test { value: 0x80_0000 }.write_be(&mut data).is_err());
+/// ```
+#[binrw::writer(writer, endian)]
+pub fn write_i24(value: &i32) -> binrw::BinResult<()> {
+    if !(-0x80_0000..0x80_0000).contains(value) {
+        return int_out_of_range(writer, value, 24);
+    }
+    // Lint: Truncation to the low 24 bits is intended
+    #[allow(clippy::cast_sign_loss)]
+    write_uint::<_, 3>(writer, endian, *value as u64)
+}
+
+/// Reads a 48-bit unsigned integer.
+///
+/// # Errors
+///
+/// If reading fails, an [`Error`] variant will be returned.
+///
+/// # Examples
+///
+/// ```
+/// # use binrw::{prelude::*, io::Cursor};
+/// #[derive(BinRead)]
+/// # #[derive(Debug, PartialEq)]
+/// struct Test {
+///     #[br(parse_with = binrw::helpers::read_u48)]
+///     value: u64,
+/// }
+/// #
+/// # assert_eq!(
+/// #     Test::read_be(&mut Cursor::new(b"\x01\x02\x03\x04\x05\x06")).unwrap(),
+/// #     Test { value: 0x0102_0304_0506 }
+/// # );
+/// # assert_eq!(
+/// #     Test::read_le(&mut Cursor::new(b"\x06\x05\x04\x03\x02\x01")).unwrap(),
+/// #     Test { value: 0x0102_0304_0506 }
+/// # );
+/// ```
+#[binrw::parser(reader, endian)]
+pub fn read_u48() -> binrw::BinResult<u64> {
+    read_uint::<_, 6>(reader, endian)
+}
+
+/// Writes a 48-bit unsigned integer.
+///
+/// # Errors
+///
+/// If the value is too large to fit in 48 bits, an
+/// [`Error::AssertFail`] will be returned.
+///
+/// # Examples
+///
+/// ```
+/// # use binrw::{prelude::*, io::Cursor};
+/// #[derive(BinWrite)]
+/// struct Test {
+///     #[bw(write_with = binrw::helpers::write_u48)]
+///     value: u64,
+/// }
+/// #
+/// # let mut data = Cursor::new(vec![]);
+/// # Test { value: 0x0102_0304_0506 }.write_le(&mut data).unwrap();
+/// # assert_eq!(data.get_ref(), &[6, 5, 4, 3, 2, 1]);
+/// # let mut data = Cursor::new(vec![]);
+/// # assert!(Test { value: 1 << 48 }.write_be(&mut data).is_err());
+/// ```
+#[binrw::writer(writer, endian)]
+pub fn write_u48(value: &u64) -> binrw::BinResult<()> {
+    if *value >= 1 << 48 {
+        return int_out_of_range(writer, value, 48);
+    }
+    write_uint::<_, 6>(writer, endian, *value)
+}
+
+/// Reads a 48-bit signed integer.
+///
+/// # Errors
+///
+/// If reading fails, an [`Error`] variant will be returned.
+///
+/// # Examples
+///
+/// ```
+/// # use binrw::{prelude::*, io::Cursor};
+/// #[derive(BinRead)]
+/// # #[derive(Debug, PartialEq)]
+/// struct Test {
+///     #[br(parse_with = binrw::helpers::read_i48)]
+///     value: i64,
+/// }
+/// #
+/// # assert_eq!(
+/// #     Test::read_le(&mut Cursor::new(b"\xfe\xff\xff\xff\xff\xff")).unwrap(),
+/// #     Test { value: -2 }
+/// # );
+/// ```
+#[binrw::parser(reader, endian)]
+pub fn read_i48() -> binrw::BinResult<i64> {
+    read_uint::<_, 6>(reader, endian).map(|value| sign_extend(value, 48))
+}
+
+/// Writes a 48-bit signed integer.
+///
+/// # Errors
+///
+/// If the value is out of range of a 48-bit signed integer, an
+/// [`Error::AssertFail`] will be returned.
+///
+/// # Examples
+///
+/// ```
+/// # use binrw::{prelude::*, io::Cursor};
+/// #[derive(BinWrite)]
+/// struct Test {
+///     #[bw(write_with = binrw::helpers::write_i48)]
+///     value: i64,
+/// }
+/// #
+/// # let mut data = Cursor::new(vec![]);
+/// # Test { value: -2 }.write_le(&mut data).unwrap();
+/// # assert_eq!(data.get_ref(), &[0xfe, 0xff, 0xff, 0xff, 0xff, 0xff]);
+/// # let mut data = Cursor::new(vec![]);
+/// # assert!(Test { value: -(1 << 47) - 1 }.write_be(&mut data).is_err());
+/// ```
+#[binrw::writer(writer, endian)]
+pub fn write_i48(value: &i64) -> binrw::BinResult<()> {
+    if !(-(1 << 47)..1 << 47).contains(value) {
+        return int_out_of_range(writer, value, 48);
+    }
+    // Lint: Truncation to the low 48 bits is intended
+    #[allow(clippy::cast_sign_loss)]
+    write_uint::<_, 6>(writer, endian, *value as u64)
+}
+
+fn read_uint<R: Read, const N: usize>(reader: &mut R, endian: Endian) -> BinResult<u64> {
+    let mut buf = [0; 8];
+    match endian {
+        Endian::Little => {
+            reader.read_exact(&mut buf[..N])?;
+            Ok(u64::from_le_bytes(buf))
+        }
+        Endian::Big => {
+            reader.read_exact(&mut buf[8 - N..])?;
+            Ok(u64::from_be_bytes(buf))
+        }
+    }
+}
+
+fn write_uint<W: Write, const N: usize>(
+    writer: &mut W,
+    endian: Endian,
+    value: u64,
+) -> BinResult<()> {
+    match endian {
+        Endian::Little => writer.write_all(&value.to_le_bytes()[..N]),
+        Endian::Big => writer.write_all(&value.to_be_bytes()[8 - N..]),
+    }
+    .map_err(Into::into)
+}
+
+// Lint: The shifts move the sign bit of the value into the sign bit of `i64`
+#[allow(clippy::cast_possible_wrap)]
+fn sign_extend(value: u64, bits: u32) -> i64 {
+    ((value << (64 - bits)) as i64) >> (64 - bits)
+}
+
+fn int_out_of_range<W: Seek, T: core::fmt::Display>(
+    writer: &mut W,
+    value: T,
+    bits: u32,
+) -> BinResult<()> {
+    Err(Error::AssertFail {
+        pos: writer.stream_position()?,
+        message: alloc::format!("value {value} out of range of {bits}-bit integer"),
+    })
+}
+
+/// Reads an unsigned [LEB128](https://en.wikipedia.org/wiki/LEB128)
+/// variable-length integer.
+///
+/// This is the same encoding used for protobuf varints and for the
+/// `uleb128` values in DWARF and WebAssembly.
+///
+/// # Errors
+///
+/// If the encoded value does not fit in a [`u64`], an
+/// [`Error::AssertFail`] will be returned.
+///
+/// # Examples
+///
+/// ```
+/// # use binrw::{prelude::*, io::Cursor};
+/// #[derive(BinRead)]
+/// # #[derive(Debug, PartialEq)]
+/// struct Test {
+///     #[br(parse_with = binrw::helpers::read_uleb128)]
+///     value: u64,
+/// }
+/// #
+/// # assert_eq!(
+/// #     Test::read_le(&mut Cursor::new(b"\xe5\x8e\x26")).unwrap(),
+/// #     Test { value: 624_485 }
+/// # );
+/// ```
+#[binrw::parser(reader)]
+pub fn read_uleb128() -> binrw::BinResult<u64> {
+    let pos = reader.stream_position()?;
+    let mut value = 0;
+    let mut shift = 0;
+    loop {
+        let byte = <u8>::read_options(reader, Endian::Little, ())?;
+        // Only one bit of the tenth byte fits in 64 bits
+        if shift == 63 && byte > 1 {
+            return Err(varint_overflow(pos));
+        }
+        value |= u64::from(byte & 0x7f) << shift;
+        if byte & 0x80 == 0 {
+            return Ok(value);
+        }
+        shift += 7;
+    }
+}
+
+/// Writes an unsigned [LEB128](https://en.wikipedia.org/wiki/LEB128)
+/// variable-length integer.
+///
+/// This is the same encoding used for protobuf varints and for the
+/// `uleb128` values in DWARF and WebAssembly.
+///
+/// # Errors
+///
+/// If writing fails, an [`Error`] variant will be returned.
+///
+/// # Examples
+///
+/// ```
+/// # use binrw::{prelude::*, io::Cursor};
+/// #[derive(BinWrite)]
+/// struct Test {
+///     #[bw(write_with = binrw::helpers::write_uleb128)]
+///     value: u64,
+/// }
+/// #
+/// # let mut data = Cursor::new(vec![]);
+/// # Test { value: 624_485 }.write_le(&mut data).unwrap();
+/// # assert_eq!(data.get_ref(), b"\xe5\x8e\x26");
+/// ```
+#[binrw::writer(writer)]
+pub fn write_uleb128(value: &u64) -> binrw::BinResult<()> {
+    let mut buf = [0; 10];
+    let mut len = 0;
+    let mut value = *value;
+    loop {
+        // Lint: Truncation to the low 7 bits is intended
+        #[allow(clippy::cast_possible_truncation)]
+        let byte = (value & 0x7f) as u8;
+        value >>= 7;
+        if value == 0 {
+            buf[len] = byte;
+            len += 1;
+            break;
+        }
+        buf[len] = byte | 0x80;
+        len += 1;
+    }
+    writer.write_all(&buf[..len]).map_err(Into::into)
+}
+
+/// Reads a signed [LEB128](https://en.wikipedia.org/wiki/LEB128)
+/// variable-length integer.
+///
+/// # Errors
+///
+/// If the encoded value does not fit in an [`i64`], an
+/// [`Error::AssertFail`] will be returned.
+///
+/// # Examples
+///
+/// ```
+/// # use binrw::{prelude::*, io::Cursor};
+/// #[derive(BinRead)]
+/// # #[derive(Debug, PartialEq)]
+/// struct Test {
+///     #[br(parse_with = binrw::helpers::read_sleb128)]
+///     value: i64,
+/// }
+/// #
+/// # assert_eq!(
+/// #     Test::read_le(&mut Cursor::new(b"\xc0\xbb\x78")).unwrap(),
+/// #     Test { value: -123_456 }
+/// # );
+/// ```
+#[binrw::parser(reader)]
+pub fn read_sleb128() -> binrw::BinResult<i64> {
+    let pos = reader.stream_position()?;
+    let mut value = 0;
+    let mut shift = 0;
+    loop {
+        let byte = <u8>::read_options(reader, Endian::Little, ())?;
+        // Only the sign bit of the tenth byte fits in 64 bits, so the rest of
+        // the byte must be its sign extension
+        if shift == 63 && byte != 0 && byte != 0x7f {
+            return Err(varint_overflow(pos));
+        }
+        value |= i64::from(byte & 0x7f) << shift;
+        shift += 7;
+        if byte & 0x80 == 0 {
+            if shift < 64 && byte & 0x40 != 0 {
+                value |= -1 << shift;
+            }
+            return Ok(value);
+        }
+    }
+}
+
+/// Writes a signed [LEB128](https://en.wikipedia.org/wiki/LEB128)
+/// variable-length integer.
+///
+/// # Errors
+///
+/// If writing fails, an [`Error`] variant will be returned.
+///
+/// # Examples
+///
+/// ```
+/// # use binrw::{prelude::*, io::Cursor};
+/// #[derive(BinWrite)]
+/// struct Test {
+///     #[bw(write_with = binrw::helpers::write_sleb128)]
+///     value: i64,
+/// }
+/// #
+/// # let mut data = Cursor::new(vec![]);
+/// # Test { value: -123_456 }.write_le(&mut data).unwrap();
+/// # assert_eq!(data.get_ref(), b"\xc0\xbb\x78");
+/// ```
+#[binrw::writer(writer)]
+pub fn write_sleb128(value: &i64) -> binrw::BinResult<()> {
+    let mut buf = [0; 10];
+    let mut len = 0;
+    let mut value = *value;
+    loop {
+        // Lint: Truncation to the low 7 bits is intended
+        #[allow(clippy::cast_possible_truncation, clippy::cast_sign_loss)]
+        let byte = (value & 0x7f) as u8;
+        value >>= 7;
+        if (value == 0 && byte & 0x40 == 0) || (value == -1 && byte & 0x40 != 0) {
+            buf[len] = byte;
+            len += 1;
+            break;
+        }
+        buf[len] = byte | 0x80;
+        len += 1;
+    }
+    writer.write_all(&buf[..len]).map_err(Into::into)
+}
+
+/// Reads a big-endian variable-length quantity, as used in MIDI files.
+///
+/// Each byte holds seven bits of the value, starting with the most
+/// significant bits. The high bit of each byte is set if more bytes follow.
+///
+/// # Errors
+///
+/// If the encoded value does not fit in a [`u64`], an
+/// [`Error::AssertFail`] will be returned.
+///
+/// # Examples
+///
+/// ```
+/// # use binrw::{prelude::*, io::Cursor};
+/// #[derive(BinRead)]
+/// # #[derive(Debug, PartialEq)]
+/// struct Test {
+///     #[br(parse_with = binrw::helpers::read_vlq)]
+///     value: u64,
+/// }
+/// #
+/// # assert_eq!(
+/// #     Test::read_be(&mut Cursor::new(b"\x81\x80\x00")).unwrap(),
+/// #     Test { value: 0x4000 }
+/// # );
+/// ```
+#[binrw::parser(reader)]
+pub fn read_vlq() -> binrw::BinResult<u64> {
+    let pos = reader.stream_position()?;
+    let mut value = 0_u64;
+    loop {
+        let byte = <u8>::read_options(reader, Endian::Big, ())?;
+        if value >> 57 != 0 {
+            return Err(varint_overflow(pos));
+        }
+        value = (value << 7) | u64::from(byte & 0x7f);
+        if byte & 0x80 == 0 {
+            return Ok(value);
+        }
+    }
+}
+
+/// Writes a big-endian variable-length quantity, as used in MIDI files.
+///
+/// Each byte holds seven bits of the value, starting with the most
+/// significant bits. The high bit of each byte is set if more bytes follow.
+///
+/// # Errors
+///
+/// If writing fails, an [`Error`] variant will be returned.
+///
+/// # Examples
+///
+/// ```
+/// # use binrw::{prelude::*, io::Cursor};
+/// #[derive(BinWrite)]
+/// struct Test {
+///     #[bw(write_with = binrw::helpers::write_vlq)]
+///     value: u64,
+/// }
+/// #
+/// # let mut data = Cursor::new(vec![]);
+/// # Test { value: 0x4000 }.write_be(&mut data).unwrap();
+/// # assert_eq!(data.get_ref(), b"\x81\x80\x00");
+/// ```
+#[binrw::writer(writer)]
+pub fn write_vlq(value: &u64) -> binrw::BinResult<()> {
+    let mut buf = [0; 10];
+    let mut start = buf.len();
+    let mut value = *value;
+    let mut continuation = 0;
+    loop {
+        start -= 1;
+        // Lint: Truncation to the low 7 bits is intended
+        #[allow(clippy::cast_possible_truncation)]
+        let byte = (value & 0x7f) as u8;
+        buf[start] = byte | continuation;
+        continuation = 0x80;
+        value >>= 7;
+        if value == 0 {
+            break;
+        }
+    }
+    writer.write_all(&buf[start..]).map_err(Into::into)
+}
+
+/// Reads a [zigzag](https://protobuf.dev/programming-guides/encoding/#signed-ints)
+/// encoded signed LEB128 variable-length integer, as used for protobuf
+/// `sint32` and `sint64` fields.
+///
+/// # Errors
+///
+/// If the encoded value does not fit in an [`i64`], an
+/// [`Error::AssertFail`] will be returned.
+///
+/// # Examples
+///
+/// ```
+/// # use binrw::{prelude::*, io::Cursor};
+/// #[derive(BinRead)]
+/// # #[derive(Debug, PartialEq)]
+/// struct Test {
+///     #[br(parse_with = binrw::helpers::read_zigzag_leb128)]
+///     value: i64,
+/// }
+/// #
+/// # assert_eq!(
+/// #     Test::read_le(&mut Cursor::new(b"\x03")).unwrap(),
+/// #     Test { value: -2 }
+/// # );
+/// ```
+#[binrw::parser(reader, endian)]
+pub fn read_zigzag_leb128() -> binrw::BinResult<i64> {
+    read_uleb128(reader, endian, ()).map(zigzag_decode)
+}
+
+/// Writes a [zigzag](https://protobuf.dev/programming-guides/encoding/#signed-ints)
+/// encoded signed LEB128 variable-length integer, as used for protobuf
+/// `sint32` and `sint64` fields.
+///
+/// # Errors
+///
+/// If writing fails, an [`Error`] variant will be returned.
+///
+/// # Examples
+///
+/// ```
+/// # use binrw::{prelude::*, io::Cursor};
+/// #[derive(BinWrite)]
+/// struct Test {
+///     #[bw(write_with = binrw::helpers::write_zigzag_leb128)]
+///     value: i64,
+/// }
+/// #
+/// # let mut data = Cursor::new(vec![]);
+/// # Test { value: -2 }.write_le(&mut data).unwrap();
+/// # assert_eq!(data.get_ref(), b"\x03");
+/// ```
+#[binrw::writer(writer, endian)]
+pub fn write_zigzag_leb128(value: &i64) -> binrw::BinResult<()> {
+    write_uleb128(&zigzag_encode(*value), writer, endian, ())
+}
+
+/// Decodes a [zigzag](https://protobuf.dev/programming-guides/encoding/#signed-ints)
+/// encoded signed integer.
+///
+/// Zigzag encoding maps signed integers to unsigned integers so that values
+/// with a small magnitude have a small encoding: 0 is 0, -1 is 1, 1 is 2, -2
+/// is 3, and so on. Values of any width can be decoded by widening them to
+/// [`u64`] first.
+///
+/// # Examples
+///
+/// ```
+/// # use binrw::{prelude::*, io::Cursor};
+/// use binrw::helpers::{zigzag_decode, zigzag_encode};
+///
+/// #[binrw]
+/// # #[derive(Debug, PartialEq)]
+/// #[brw(little)]
+/// struct Test {
+///     #[br(map = |raw: u32| zigzag_decode(raw.into()) as i32)]
+///     #[bw(map = |value| zigzag_encode((*value).into()) as u32)]
+///     value: i32,
+/// }
+///
+/// let test = Test::read(&mut Cursor::new(b"\x07\0\0\0")).unwrap();
+/// assert_eq!(test, Test { value: -4 });
+///
+/// let mut data = Cursor::new(vec![]);
+/// test.write(&mut data).unwrap();
+/// assert_eq!(data.into_inner(), b"\x07\0\0\0");
+/// ```
+#[must_use]
+pub fn zigzag_decode(value: u64) -> i64 {
+    // Lint: The casts reinterpret the bits of the value
+    #[allow(clippy::cast_possible_wrap)]
+    let result = ((value >> 1) as i64) ^ -((value & 1) as i64);
+    result
+}
+
+/// Encodes a signed integer using
+/// [zigzag](https://protobuf.dev/programming-guides/encoding/#signed-ints)
+/// encoding.
+///
+/// See [`zigzag_decode`] for details.
+#[must_use]
+pub fn zigzag_encode(value: i64) -> u64 {
+    // Lint: The cast reinterprets the bits of the value
+    #[allow(clippy::cast_sign_loss)]
+    let result = ((value << 1) ^ (value >> 63)) as u64;
+    result
+}
+
+fn varint_overflow(pos: u64) -> Error {
+    Error::AssertFail {
+        pos,
+        message: "variable-length integer too large for 64-bit integer".into(),
+    }
+}
+
+/// Reads an IEEE 754 half-precision (16-bit) float into an [`f32`].
+///
+/// # Errors
+///
+/// If reading fails, an [`Error`] variant will be returned.
+///
+/// # Examples
+///
+/// ```
+/// # use binrw::{prelude::*, io::Cursor};
+/// #[derive(BinRead)]
+/// # #[derive(Debug, PartialEq)]
+/// struct Test {
+///     #[br(parse_with = binrw::helpers::read_f16)]
+///     value: f32,
+/// }
+/// #
+/// # assert_eq!(
+/// #     Test::read_be(&mut Cursor::new(b"\x3e\x00")).unwrap(),
+/// #     Test { value: 1.5 }
+/// # );
+/// # assert_eq!(
+/// #     Test::read_le(&mut Cursor::new(b"\x00\xc0")).unwrap(),
+/// #     Test { value: -2.0 }
+/// # );
+/// ```
+#[binrw::parser(reader, endian)]
+pub fn read_f16() -> binrw::BinResult<f32> {
+    <u16>::read_options(reader, endian, ()).map(f16_to_f32)
+}
+
+/// Writes an [`f32`] as an IEEE 754 half-precision (16-bit) float.
+///
+/// Values are rounded to the nearest representable value, with ties rounding
+/// to even. Values too large to represent are written as infinity.
+///
+/// # Errors
+///
+/// If writing fails, an [`Error`] variant will be returned.
+///
+/// # Examples
+///
+/// ```
+/// # use binrw::{prelude::*, io::Cursor};
+/// #[derive(BinWrite)]
+/// # #[derive(Debug, PartialEq)]
+/// struct Test {
+///     #[bw(write_with = binrw::helpers::write_f16)]
+///     value: f32,
+/// }
+/// #
+/// # let mut data = Cursor::new(vec![]);
+/// # Test { value: 1.5 }.write_be(&mut data).unwrap();
+/// # assert_eq!(data.get_ref(), &[0x3e, 0]);
+/// # let mut data = Cursor::new(vec![]);
+/// # Test { value: -2.0 }.write_le(&mut data).unwrap();
+/// # assert_eq!(data.get_ref(), &[0, 0xc0]);
+/// ```
+#[binrw::writer(writer, endian)]
+pub fn write_f16(value: &f32) -> binrw::BinResult<()> {
+    f32_to_f16(*value).write_options(writer, endian, ())
+}
+
+fn f16_to_f32(half: u16) -> f32 {
+    let sign = u32::from(half & 0x8000) << 16;
+    let exp = u32::from((half >> 10) & 0x1f);
+    let man = u32::from(half & 0x3ff);
+
+    match (exp, man) {
+        (0, 0) => f32::from_bits(sign),
+        // Subnormal values are the mantissa times 2^-24
+        (0, _) => {
+            let value = f32::from(half & 0x3ff) * f32::from_bits(0x3380_0000);
+            if sign == 0 {
+                value
+            } else {
+                -value
+            }
+        }
+        (0x1f, _) => f32::from_bits(sign | 0x7f80_0000 | (man << 13)),
+        _ => f32::from_bits(sign | ((exp + 127 - 15) << 23) | (man << 13)),
+    }
+}
+
+// Lint: All casts are of values which have been masked or shifted to fit
+#[allow(
+    clippy::cast_possible_truncation,
+    clippy::cast_possible_wrap,
+    clippy::cast_sign_loss
+)]
+fn f32_to_f16(value: f32) -> u16 {
+    let bits = value.to_bits();
+    let sign = ((bits >> 16) & 0x8000) as u16;
+    let exp = (bits >> 23) & 0xff;
+    let man = bits & 0x7f_ffff;
+
+    // Infinity and NaN, keeping NaN from becoming infinity
+    if exp == 0xff {
+        let nan = if man == 0 { 0 } else { 0x200 };
+        return sign | 0x7c00 | nan | (man >> 13) as u16;
+    }
+
+    let half_exp = exp as i32 - 127 + 15;
+
+    if half_exp >= 0x1f {
+        return sign | 0x7c00;
+    }
+
+    // Rounds to nearest, ties to even, by checking the highest discarded bit
+    // and then either any lower discarded bit or the lowest kept bit
+    let round = |man: u32, shift: u32| {
+        let round_bit = 1 << (shift - 1);
+        let round_up = man & round_bit != 0 && man & (3 * round_bit - 1) != 0;
+        (man >> shift) as u16 + u16::from(round_up)
     };
-    writer.write_all(&buf[range]).map_err(Into::into)
+
+    if half_exp <= 0 {
+        // Too small even for a subnormal value
+        if half_exp < -10 {
+            return sign;
+        }
+
+        return sign | round(man | 0x80_0000, (14 - half_exp) as u32);
+    }
+
+    // A carry from rounding the mantissa correctly increments the exponent
+    sign | (((half_exp as u16) << 10) + round(man, 13))
+}
+
+/// Writes every item from an iterator.
+///
+/// This can be called from a [`BinWrite`] implementation to write items as
+/// they are generated, without collecting them into a [`Vec`] first.
+///
+/// # Errors
+///
+/// If writing any item fails, an [`Error`](crate::Error) variant will be
+/// returned.
+///
+/// # Examples
+///
+/// ```
+/// # use binrw::{prelude::*, io::{Cursor, Seek, Write}, Endian};
+/// use binrw::helpers::write_iter;
+///
+/// struct Squares(u16);
+///
+/// impl BinWrite for Squares {
+///     type Args<'a> = ();
+///
+///     fn write_options<W: Write + Seek>(
+///         &self,
+///         writer: &mut W,
+///         endian: Endian,
+///         args: Self::Args<'_>,
+///     ) -> BinResult<()> {
+///         write_iter((1..=self.0).map(|n| n * n), writer, endian, args)
+///     }
+/// }
+///
+/// let mut data = Cursor::new(vec![]);
+/// Squares(3).write_be(&mut data).unwrap();
+/// assert_eq!(data.get_ref(), &[0, 1, 0, 4, 0, 9]);
+/// ```
+pub fn write_iter<W, It, Arg>(it: It, writer: &mut W, endian: Endian, args: Arg) -> BinResult<()>
+where
+    W: Write + Seek,
+    It: IntoIterator,
+    It::Item: for<'a> BinWrite<Args<'a> = Arg>,
+    Arg: Clone,
+{
+    for item in it {
+        item.write_options(writer, endian, args.clone())?;
+    }
+
+    Ok(())
 }
 
 fn not_enough_bytes<T>(_: T) -> Error {
@@ -555,10 +1514,44 @@ fn not_enough_bytes<T>(_: T) -> Error {
     ))
 }
 
+/// Reads `n` items into the end of `list` in bulk if it is a `Vec` of a
+/// primitive integer type.
+///
+/// Returns `false` without reading anything if `list` is any other type, in
+/// which case the caller must read the items one at a time.
+pub(crate) fn read_vec_fast<R: Read + Seek>(
+    list: &mut dyn core::any::Any,
+    reader: &mut R,
+    endian: Endian,
+    n: usize,
+) -> BinResult<bool> {
+    vec_fast_int!(try (i8 i16 u16 i32 u32 i64 u64 i128 u128) using (list, reader, endian, n) else {
+        // This extra branch for `Vec<u8>` makes it faster than
+        // `vec_fast_int`, but *only* because `vec_fast_int` is not allowed
+        // to use unsafe code to eliminate the unnecessary zero-fill.
+        // Otherwise, performance would be identical and it could be
+        // deleted.
+        if let Some(bytes) = list.downcast_mut::<Vec<u8>>() {
+            bytes.reserve_exact(n);
+            let byte_count = reader
+                .take(n.try_into().map_err(not_enough_bytes)?)
+                .read_to_end(bytes)?;
+
+            if byte_count == n {
+                Ok(true)
+            } else {
+                Err(not_enough_bytes(()))
+            }
+        } else {
+            Ok(false)
+        }
+    })
+}
+
 macro_rules! vec_fast_int {
     (try ($($Ty:ty)+) using ($list:expr, $reader:expr, $endian:expr, $count:expr) else { $($else:tt)* }) => {
-        $(if let Some(list) = <dyn core::any::Any>::downcast_mut::<Vec<$Ty>>(&mut $list) {
-            let mut start = 0;
+        $(if let Some(list) = $list.downcast_mut::<Vec<$Ty>>() {
+            let mut start = list.len();
             let mut remaining = $count;
             // Allocating and reading from the source in chunks is done to keep
             // a bad `count` from causing huge memory allocations that are
@@ -591,11 +1584,11 @@ macro_rules! vec_fast_int {
                     || (cfg!(target_endian = "little") && $endian == crate::Endian::Big)
                 )
             {
-                for value in list.iter_mut() {
+                for value in &mut list[start - $count..] {
                     *value = value.swap_bytes();
                 }
             }
-            Ok($list)
+            Ok(true)
         } else)* {
             $($else)*
         }
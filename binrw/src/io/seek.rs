@@ -9,17 +9,36 @@ use alloc::{string::String, vec::Vec};
 ///
 /// This is useful when reading or writing from unseekable streams where binrw
 /// does not *actually* need to seek to successfully parse or write the data.
+///
+/// Seeking to the current position always succeeds. Readers created with
+/// [`NoSeek::with_forward_seek`] also allow seeking forwards by reading and
+/// discarding data. Seeking back by one byte immediately after a read also
+/// succeeds, which lets binrw check for the end of the stream. All other
+/// seeks return an error.
 pub struct NoSeek<T> {
     /// The original stream.
     inner: T,
     /// The virtual position of the seekable stream.
     pos: u64,
+    /// The function used to skip data when seeking forwards, if supported.
+    skip: Option<fn(&mut T, u64) -> super::Result<()>>,
+    /// The last byte returned by a read, which can be un-read by seeking back
+    /// one byte.
+    last: Option<u8>,
+    /// A byte which was un-read and will be returned by the next read.
+    peeked: Option<u8>,
 }
 
 impl<T> NoSeek<T> {
     /// Creates a new seekable wrapper for the given value.
     pub fn new(inner: T) -> Self {
-        NoSeek { inner, pos: 0 }
+        NoSeek {
+            inner,
+            pos: 0,
+            skip: None,
+            last: None,
+            peeked: None,
+        }
     }
 
     /// Gets a mutable reference to the underlying value.
@@ -38,13 +57,71 @@ impl<T> NoSeek<T> {
     }
 }
 
+impl<T: super::Read> NoSeek<T> {
+    /// Creates a new seekable wrapper for the given reader which also allows
+    /// seeking forwards.
+    ///
+    /// Seeking forwards reads and discards data from the reader until the new
+    /// position is reached. Seeking backwards returns an error, except for
+    /// seeking back by one byte immediately after a read.
+    pub fn with_forward_seek(inner: T) -> Self {
+        NoSeek {
+            inner,
+            pos: 0,
+            skip: Some(skip),
+            last: None,
+            peeked: None,
+        }
+    }
+}
+
 impl<T> super::Seek for NoSeek<T> {
     fn seek(&mut self, pos: SeekFrom) -> super::Result<u64> {
-        match pos {
-            SeekFrom::Start(n) if self.pos == n => Ok(n),
-            SeekFrom::Current(0) => Ok(self.pos),
+        let target = match pos {
+            SeekFrom::Start(n) => Some(n),
+            SeekFrom::Current(n) => self.pos.checked_add_signed(n),
             // https://github.com/rust-lang/rust/issues/86442
-            _ => Err(Error::new(ErrorKind::Other, "seek on unseekable file")),
+            SeekFrom::End(_) => {
+                return Err(Error::new(
+                    ErrorKind::Other,
+                    "cannot seek from end of unseekable stream",
+                ))
+            }
+        };
+
+        let last = self.last.take();
+        match (target, self.skip) {
+            (Some(n), _) if n == self.pos => Ok(n),
+            (Some(n), _)
+                if Some(n) == self.pos.checked_sub(1)
+                    && last.is_some()
+                    && self.peeked.is_none() =>
+            {
+                self.peeked = last;
+                self.pos = n;
+                Ok(n)
+            }
+            (Some(n), Some(skip)) if n > self.pos => {
+                let mut count = n - self.pos;
+                if self.peeked.take().is_some() {
+                    count -= 1;
+                }
+                skip(&mut self.inner, count)?;
+                self.pos = n;
+                Ok(n)
+            }
+            (Some(n), _) if n < self.pos => Err(Error::new(
+                ErrorKind::Other,
+                "cannot seek backwards in unseekable stream",
+            )),
+            (Some(_), _) => Err(Error::new(
+                ErrorKind::Other,
+                "cannot seek forwards in unseekable stream",
+            )),
+            (None, _) => Err(Error::new(
+                ErrorKind::InvalidInput,
+                "invalid seek to a negative or overflowing position",
+            )),
         }
     }
 
@@ -55,39 +132,90 @@ impl<T> super::Seek for NoSeek<T> {
 
 impl<T: super::Read> super::Read for NoSeek<T> {
     fn read(&mut self, buf: &mut [u8]) -> super::Result<usize> {
-        let n = self.inner.read(buf)?;
-        self.pos += n as u64;
+        let n = match (self.peeked.take(), buf.first_mut()) {
+            (Some(byte), Some(first)) => {
+                *first = byte;
+                1
+            }
+            (peeked, _) => {
+                self.peeked = peeked;
+                self.inner.read(buf)?
+            }
+        };
+        self.consumed(&buf[..n]);
         Ok(n)
     }
 
     #[cfg(feature = "std")]
     fn read_vectored(&mut self, bufs: &mut [std::io::IoSliceMut<'_>]) -> super::Result<usize> {
+        if self.peeked.is_some() {
+            let buf = bufs
+                .iter_mut()
+                .find(|buf| !buf.is_empty())
+                .map_or(&mut [][..], |buf| &mut **buf);
+            return self.read(buf);
+        }
+
         let n = self.inner.read_vectored(bufs)?;
+        self.last = None;
         self.pos += n as u64;
         Ok(n)
     }
 
     fn read_to_end(&mut self, buf: &mut Vec<u8>) -> super::Result<usize> {
-        let n = self.inner.read_to_end(buf)?;
-        self.pos += n as u64;
-        Ok(n)
+        let start = buf.len();
+        buf.extend(self.peeked.take());
+        self.inner.read_to_end(buf)?;
+        self.consumed(&buf[start..]);
+        Ok(buf.len() - start)
     }
 
     fn read_to_string(&mut self, buf: &mut String) -> super::Result<usize> {
-        let n = self.inner.read_to_string(buf)?;
-        self.pos += n as u64;
-        Ok(n)
+        if self.peeked.is_none() {
+            let n = self.inner.read_to_string(buf)?;
+            self.consumed(&buf.as_bytes()[buf.len() - n..]);
+            return Ok(n);
+        }
+
+        let mut bytes = Vec::new();
+        self.read_to_end(&mut bytes)?;
+        let s = core::str::from_utf8(&bytes).map_err(|_| {
+            Error::new(ErrorKind::InvalidData, "stream did not contain valid UTF-8")
+        })?;
+        buf.push_str(s);
+        Ok(bytes.len())
     }
 
     fn read_exact(&mut self, buf: &mut [u8]) -> super::Result<()> {
-        self.inner.read_exact(buf)?;
-        self.pos += buf.len() as u64;
+        let rest = match (self.peeked.take(), buf.split_first_mut()) {
+            (Some(byte), Some((first, rest))) => {
+                *first = byte;
+                rest
+            }
+            (peeked, _) => {
+                self.peeked = peeked;
+                &mut *buf
+            }
+        };
+        self.inner.read_exact(rest)?;
+        self.consumed(buf);
         Ok(())
     }
 }
 
+impl<T> NoSeek<T> {
+    /// Records that `buf` was read from the stream.
+    fn consumed(&mut self, buf: &[u8]) {
+        if let Some(&byte) = buf.last() {
+            self.last = Some(byte);
+        }
+        self.pos += buf.len() as u64;
+    }
+}
+
 impl<T: super::Write> super::Write for NoSeek<T> {
     fn write(&mut self, buf: &[u8]) -> super::Result<usize> {
+        self.last = None;
         let n = self.inner.write(buf)?;
         self.pos += n as u64;
         Ok(n)
@@ -99,14 +227,36 @@ impl<T: super::Write> super::Write for NoSeek<T> {
 
     #[cfg(feature = "std")]
     fn write_vectored(&mut self, bufs: &[std::io::IoSlice<'_>]) -> super::Result<usize> {
+        self.last = None;
         let n = self.inner.write_vectored(bufs)?;
         self.pos += n as u64;
         Ok(n)
     }
 
     fn write_all(&mut self, buf: &[u8]) -> super::Result<()> {
+        self.last = None;
         self.inner.write_all(buf)?;
         self.pos += buf.len() as u64;
         Ok(())
     }
 }
+
+/// Reads and discards `count` bytes from the given reader.
+fn skip<T: super::Read>(reader: &mut T, mut count: u64) -> super::Result<()> {
+    let mut buf = [0; 256];
+    while count != 0 {
+        let len = buf.len().min(count.try_into().unwrap_or(usize::MAX));
+        match reader.read(&mut buf[..len]) {
+            Ok(0) => {
+                return Err(Error::new(
+                    ErrorKind::UnexpectedEof,
+                    "end of stream reached while seeking forwards",
+                ))
+            }
+            Ok(n) => count -= n as u64,
+            Err(err) if err.kind() == ErrorKind::Interrupted => {}
+            Err(err) => return Err(err),
+        }
+    }
+    Ok(())
+}
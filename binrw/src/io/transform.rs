@@ -0,0 +1,139 @@
+//! Read adapter which transforms each byte read from an underlying reader.
+
+use super::{Error, ErrorKind, Read, Result, Seek, SeekFrom};
+
+/// Read adapter which transforms each byte read from an underlying reader,
+/// with seek support.
+///
+/// The transform function receives the offset of each byte from the position
+/// where the adapter was created, and the byte read from the underlying
+/// reader. It returns the byte to give to the caller. Because the function
+/// receives the offset, it can be used for position-dependent transforms like
+/// XOR with a repeating key, even when the reader seeks. Reading from before
+/// the position where the adapter was created returns an error.
+///
+/// This is usually used with the
+/// [`map_stream`](crate::docs::attribute#stream-access-and-manipulation)
+/// directive to parse obfuscated regions of a file.
+///
+/// # Examples
+///
+/// ```
+/// # use binrw::{BinRead, BinReaderExt, io::{Cursor, TransformReader}};
+/// const KEY: &[u8] = b"\xaa\x55";
+///
+/// #[derive(BinRead)]
+/// #[br(big)]
+/// struct Archive {
+///     count: u8,
+///     #[br(
+///         count = count,
+///         map_stream = |reader| TransformReader::new(reader, |offset, byte| {
+///             byte ^ KEY[(offset % 2) as usize]
+///         })
+///     )]
+///     values: Vec<u16>,
+/// }
+///
+/// let archive: Archive = Cursor::new(b"\x02\xaa\x54\xaa\x57").read_be().unwrap();
+/// assert_eq!(archive.values, [1, 2]);
+/// ```
+pub struct TransformReader<T, F> {
+    inner: T,
+    transform: F,
+    start: Option<u64>,
+    pos: u64,
+}
+
+impl<T, F> TransformReader<T, F>
+where
+    F: FnMut(u64, u8) -> u8,
+{
+    /// Creates a new adapter which transforms each byte read from `inner`
+    /// using the `transform` function.
+    pub fn new(inner: T, transform: F) -> Self {
+        Self {
+            inner,
+            transform,
+            start: None,
+            pos: 0,
+        }
+    }
+}
+
+impl<T, F> TransformReader<T, F> {
+    /// Gets a reference to the underlying reader.
+    pub fn get_ref(&self) -> &T {
+        &self.inner
+    }
+
+    /// Gets a mutable reference to the underlying reader.
+    ///
+    /// Care should be taken to avoid modifying the internal I/O state of the
+    /// underlying reader as doing so may corrupt the offsets given to the
+    /// transform function.
+    pub fn get_mut(&mut self) -> &mut T {
+        &mut self.inner
+    }
+
+    /// Consumes this wrapper, returning the wrapped value.
+    pub fn into_inner(self) -> T {
+        self.inner
+    }
+}
+
+impl<T: Seek, F> TransformReader<T, F> {
+    /// Returns the position of the underlying reader where this adapter was
+    /// created.
+    fn start(&mut self) -> Result<u64> {
+        if let Some(start) = self.start {
+            Ok(start)
+        } else {
+            let start = self.inner.stream_position()?;
+            self.start = Some(start);
+            self.pos = start;
+            Ok(start)
+        }
+    }
+
+    /// Returns the offset of the underlying reader from the position where
+    /// this adapter was created.
+    fn offset(&mut self) -> Result<u64> {
+        let start = self.start()?;
+        self.pos.checked_sub(start).ok_or_else(|| {
+            Error::new(
+                ErrorKind::InvalidInput,
+                "cannot read before the start of a TransformReader",
+            )
+        })
+    }
+}
+
+impl<T, F> Read for TransformReader<T, F>
+where
+    T: Read + Seek,
+    F: FnMut(u64, u8) -> u8,
+{
+    fn read(&mut self, buf: &mut [u8]) -> Result<usize> {
+        let offset = self.offset()?;
+        let n = self.inner.read(buf)?;
+        for (byte_offset, byte) in (offset..).zip(&mut buf[..n]) {
+            *byte = (self.transform)(byte_offset, *byte);
+        }
+        self.pos += n as u64;
+        Ok(n)
+    }
+}
+
+impl<T: Seek, F> Seek for TransformReader<T, F> {
+    fn seek(&mut self, pos: SeekFrom) -> Result<u64> {
+        self.start()?;
+        self.pos = self.inner.seek(pos)?;
+        Ok(self.pos)
+    }
+
+    fn stream_position(&mut self) -> Result<u64> {
+        self.start()?;
+        Ok(self.pos)
+    }
+}
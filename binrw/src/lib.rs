@@ -22,6 +22,10 @@ extern crate std;
 pub mod __private;
 mod binread;
 mod binwrite;
+pub mod bits;
+#[cfg(feature = "std")]
+#[cfg_attr(all(doc, nightly), doc(cfg(feature = "std")))]
+pub mod diagnostics;
 pub mod docs;
 pub mod endian;
 pub mod error;
@@ -35,6 +39,9 @@ pub mod pos_value;
 pub mod punctuated;
 #[doc(hidden)]
 pub mod strings;
+pub mod time;
+#[doc(hidden)]
+pub mod write_hole;
 
 #[cfg(all(doc, not(feature = "std")))]
 use alloc::vec::Vec;
@@ -47,7 +54,8 @@ pub use {
     file_ptr::{FilePtr, FilePtr128, FilePtr16, FilePtr32, FilePtr64, FilePtr8},
     named_args::NamedArgs,
     pos_value::PosValue,
-    strings::{NullString, NullWideString},
+    strings::{FixedString, NullString, NullStringArgs, NullWideString, PascalString},
+    write_hole::WriteHole,
 };
 
 /// Derive macro generating an impl of the trait [`BinRead`].
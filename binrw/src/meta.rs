@@ -10,7 +10,14 @@
 //! [`BinWrite`]: crate::BinWrite
 
 use crate::Endian;
-use alloc::{boxed::Box, vec::Vec};
+#[cfg(target_has_atomic = "ptr")]
+use alloc::sync::Arc;
+use alloc::{
+    borrow::{Cow, ToOwned},
+    boxed::Box,
+    rc::Rc,
+    vec::Vec,
+};
 use core::marker::PhantomData;
 
 /// Types that require a magic number when parsed.
@@ -94,11 +101,31 @@ macro_rules! endian_impl {
 
 endian_impl!(() i8 u8 core::num::NonZeroU8 core::num::NonZeroI8 crate::strings::NullString => EndianKind::None);
 
-impl<T: ReadEndian + ?Sized> ReadEndian for Box<T> {
-    const ENDIAN: EndianKind = <T as ReadEndian>::ENDIAN;
+macro_rules! endian_pointer_impl {
+    ($($Ty:ident)+) => {$(
+        impl<T: ReadEndian + ?Sized> ReadEndian for $Ty<T> {
+            const ENDIAN: EndianKind = <T as ReadEndian>::ENDIAN;
+        }
+
+        impl<T: WriteEndian + ?Sized> WriteEndian for $Ty<T> {
+            const ENDIAN: EndianKind = <T as WriteEndian>::ENDIAN;
+        }
+    )+}
 }
 
-impl<T: WriteEndian + ?Sized> WriteEndian for Box<T> {
+endian_pointer_impl!(Box Rc);
+#[cfg(target_has_atomic = "ptr")]
+endian_pointer_impl!(Arc);
+
+impl<T> ReadEndian for Cow<'_, T>
+where
+    T: ToOwned + ?Sized,
+    T::Owned: ReadEndian,
+{
+    const ENDIAN: EndianKind = <T::Owned as ReadEndian>::ENDIAN;
+}
+
+impl<T: WriteEndian + ToOwned + ?Sized> WriteEndian for Cow<'_, T> {
     const ENDIAN: EndianKind = <T as WriteEndian>::ENDIAN;
 }
 
@@ -130,7 +157,15 @@ macro_rules! endian_generic_impl {
     )+}
 }
 
-endian_generic_impl!(Option Vec PhantomData);
+endian_generic_impl!(Option Vec);
+
+impl<T: ?Sized> ReadEndian for PhantomData<T> {
+    const ENDIAN: EndianKind = EndianKind::None;
+}
+
+impl<T: ?Sized> WriteEndian for PhantomData<T> {
+    const ENDIAN: EndianKind = EndianKind::None;
+}
 
 macro_rules! endian_tuple_impl {
     ($type1:ident $(, $types:ident)*) => {
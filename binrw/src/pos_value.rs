@@ -1,11 +1,14 @@
 use crate::{
-    io::{Read, Seek},
-    BinRead, BinResult, Endian,
+    io::{Read, Seek, Write},
+    BinRead, BinResult, BinWrite, Endian,
 };
 use core::fmt;
 
 /// A wrapper that stores a value’s position alongside the value.
 ///
+/// When written, only the inner value is written; the stored positions are
+/// ignored.
+///
 /// # Examples
 ///
 /// ```
@@ -19,6 +22,7 @@ use core::fmt;
 ///
 /// let val = Cursor::new(b"\xFF\xFE\xFD").read_be::<MyType>().unwrap();
 /// assert_eq!(val.b.pos, 2);
+/// assert_eq!(val.b.end, 3);
 /// assert_eq!(*val.b, 0xFD);
 /// ```
 pub struct PosValue<T> {
@@ -27,6 +31,9 @@ pub struct PosValue<T> {
 
     /// The byte position of the start of the value.
     pub pos: u64,
+
+    /// The byte position of the end of the value.
+    pub end: u64,
 }
 
 impl<T: BinRead> BinRead for PosValue<T> {
@@ -38,14 +45,29 @@ impl<T: BinRead> BinRead for PosValue<T> {
         args: Self::Args<'_>,
     ) -> BinResult<Self> {
         let pos = reader.stream_position()?;
+        let val = T::read_options(reader, endian, args)?;
 
         Ok(PosValue {
+            val,
             pos,
-            val: T::read_options(reader, endian, args)?,
+            end: reader.stream_position()?,
         })
     }
 }
 
+impl<T: BinWrite> BinWrite for PosValue<T> {
+    type Args<'a> = T::Args<'a>;
+
+    fn write_options<W: Write + Seek>(
+        &self,
+        writer: &mut W,
+        endian: Endian,
+        args: Self::Args<'_>,
+    ) -> BinResult<()> {
+        self.val.write_options(writer, endian, args)
+    }
+}
+
 impl<T> core::ops::Deref for PosValue<T> {
     type Target = T;
 
@@ -71,6 +93,7 @@ impl<T: Clone> Clone for PosValue<T> {
         Self {
             val: self.val.clone(),
             pos: self.pos,
+            end: self.end,
         }
     }
 }
@@ -1,6 +1,6 @@
 use crate::{
     error::{Backtrace, BacktraceFrame, CustomError},
-    io::{Read, Seek, SeekFrom, Write},
+    io::{ErrorKind, Read, Seek, SeekFrom, Write},
     BinRead, BinResult, BinWrite, Endian, Error,
 };
 use alloc::{boxed::Box, string::String};
@@ -36,7 +36,7 @@ pub enum AssertErrorFn<M, E> {
     Error(E),
 }
 
-pub fn assert<MsgFn, Msg, ErrorFn, Err>(
+pub fn assert<MsgFn, Msg, ErrorFn>(
     test: bool,
     pos: u64,
     error_fn: AssertErrorFn<MsgFn, ErrorFn>,
@@ -44,8 +44,7 @@ pub fn assert<MsgFn, Msg, ErrorFn, Err>(
 where
     MsgFn: Fn() -> Msg,
     Msg: Into<String> + Sized,
-    ErrorFn: Fn() -> Err,
-    Err: CustomError + 'static,
+    ErrorFn: Fn() -> Error,
 {
     if test {
         Ok(())
@@ -55,14 +54,87 @@ where
                 pos,
                 message: error_fn().into(),
             },
-            AssertErrorFn::Error(error_fn) => Error::Custom {
-                pos,
-                err: Box::new(error_fn()),
-            },
+            AssertErrorFn::Error(error_fn) => error_fn(),
         })
     }
 }
 
+// This converts errors from directives into `Error::Custom` using autoref
+// specialisation. Method lookup tries `StdCustomErr` first because its
+// receiver needs no extra reference, so an error which implements
+// `std::error::Error` is stored with `Error::custom` and is available from
+// `source()`. Any other `CustomError` falls back to `AnyCustomErr`.
+pub struct CustomErr<E>(pub Option<E>);
+
+pub trait StdCustomErr {
+    fn take_custom_err(&mut self, pos: u64) -> Error;
+}
+
+#[cfg(feature = "std")]
+impl<E: std::error::Error + Send + Sync + 'static> StdCustomErr for CustomErr<E> {
+    fn take_custom_err(&mut self, pos: u64) -> Error {
+        Error::custom(pos, self.0.take().unwrap())
+    }
+}
+
+pub trait AnyCustomErr {
+    fn take_custom_err(&mut self, pos: u64) -> Error;
+}
+
+impl<E: CustomError + 'static> AnyCustomErr for &mut CustomErr<E> {
+    fn take_custom_err(&mut self, pos: u64) -> Error {
+        Error::Custom {
+            pos,
+            err: Box::new(self.0.take().unwrap()),
+        }
+    }
+}
+
+#[cfg(feature = "std")]
+pub fn warn<MsgFn, Msg>(test: bool, pos: u64, message_fn: MsgFn)
+where
+    MsgFn: FnOnce() -> Msg,
+    Msg: Into<String>,
+{
+    if !test && crate::diagnostics::is_collecting() {
+        crate::diagnostics::warn(pos, message_fn());
+    }
+}
+
+// Without `std` there is no thread-local sink to record diagnostics into, so
+// warnings are discarded. This is documented on the `warn` directive.
+#[cfg(not(feature = "std"))]
+pub fn warn<MsgFn, Msg>(_: bool, _: u64, _: MsgFn)
+where
+    MsgFn: FnOnce() -> Msg,
+    Msg: Into<String>,
+{
+}
+
+// This is used by `recover` mode to replace a field which failed to parse with
+// its default value. The error is reported as a diagnostic so it is not
+// silently lost. The position is only looked up when diagnostics are being
+// collected, so the error path does no extra I/O otherwise.
+#[cfg(feature = "std")]
+#[must_use]
+pub fn recover<T: Default, S: Seek>(error: &Error, reader: &mut S, context: &str) -> T {
+    if crate::diagnostics::is_collecting() {
+        let pos = reader
+            .stream_position()
+            .ok()
+            .or_else(|| error.pos())
+            .unwrap_or_default();
+        crate::diagnostics::warn(pos, alloc::format!("{context}: {}", error.root_cause()));
+    }
+    T::default()
+}
+
+#[cfg(not(feature = "std"))]
+#[must_use]
+pub fn recover<T: Default, S: Seek>(_: &Error, _: &mut S, _: &str) -> T {
+    T::default()
+}
+
 // This validates the map function return value by trying to coerce it into
 // a function with the expected return type. If this is not done, the
 // compiler will emit the diagnostic on the `#[derive]`d attribute instead of
@@ -77,29 +149,119 @@ where
 }
 
 pub fn magic<R, B>(reader: &mut R, expected: B, endian: Endian) -> BinResult<()>
+where
+    B: for<'a> BinRead<Args<'a> = ()> + core::fmt::Debug + PartialEq + Sync + Send + 'static,
+    R: Read + Seek,
+{
+    let pos = reader.stream_position()?;
+    let val = B::read_options(reader, endian, ())?;
+    if val == expected {
+        Ok(())
+    } else {
+        Err(Error::BadMagic {
+            pos,
+            expected: Box::new(expected) as _,
+            found: Box::new(val) as _,
+        })
+    }
+}
+
+pub fn magic_one_of<R, B, const N: usize>(
+    reader: &mut R,
+    expected: &[B; N],
+    endian: Endian,
+) -> BinResult<B>
 where
     B: for<'a> BinRead<Args<'a> = ()>
+        + Clone
         + core::fmt::Debug
         + PartialEq
         + Sync
         + Send
-        + Clone
-        + Copy
         + 'static,
     R: Read + Seek,
 {
     let pos = reader.stream_position()?;
     let val = B::read_options(reader, endian, ())?;
-    if val == expected {
-        Ok(())
+    if expected.contains(&val) {
+        Ok(val)
     } else {
         Err(Error::BadMagic {
             pos,
+            expected: Box::new(expected.clone()) as _,
             found: Box::new(val) as _,
         })
     }
 }
 
+pub fn write_magic_one_of<W, B, const N: usize>(
+    writer: &mut W,
+    expected: &[B; N],
+    value: &B,
+    endian: Endian,
+) -> BinResult<()>
+where
+    B: for<'a> BinWrite<Args<'a> = ()> + core::fmt::Debug + PartialEq,
+    W: Write + Seek,
+{
+    if expected.contains(value) {
+        value.write_options(writer, endian, ())
+    } else {
+        Err(Error::AssertFail {
+            pos: writer.stream_position()?,
+            message: alloc::format!("magic {value:?} is not one of {expected:?}"),
+        })
+    }
+}
+
+pub fn pad_size_to<R, S>(reader: &mut R, start: u64, size: S) -> BinResult<()>
+where
+    R: Seek,
+    S: TryInto<u64>,
+{
+    let end = size
+        .try_into()
+        .ok()
+        .and_then(|size| start.checked_add(size))
+        .ok_or_else(|| Error::AssertFail {
+            pos: start,
+            message: "`pad_size_to` must be a non-negative size which fits in the stream".into(),
+        })?;
+    if reader.stream_position()? < end {
+        reader.seek(SeekFrom::Start(end))?;
+    }
+    Ok(())
+}
+
+/// Returns true if there is more data in the reader.
+///
+/// Seekable streams are checked by comparing the current position against the
+/// end of the stream. Streams which cannot seek from the end (like
+/// [`NoSeek`](crate::io::NoSeek)) are checked by reading one byte and then
+/// seeking back over it.
+pub fn not_eof<R: Read + Seek>(reader: &mut R) -> BinResult<bool> {
+    let pos = reader.stream_position()?;
+    if let Ok(end) = reader.seek(SeekFrom::End(0)) {
+        if end != pos {
+            reader.seek(SeekFrom::Start(pos))?;
+        }
+        return Ok(pos < end);
+    }
+
+    let mut buf = [0; 1];
+    loop {
+        match reader.read(&mut buf) {
+            Ok(0) => return Ok(false),
+            Ok(_) => {
+                reader.seek(SeekFrom::Current(-1))?;
+                return Ok(true);
+            }
+            Err(err) if err.kind() == ErrorKind::Interrupted => {}
+            Err(err) => return Err(err.into()),
+        }
+    }
+}
+
 pub fn parse_fn_type_hint<Ret, ParseFn, R, Args>(f: ParseFn) -> ParseFn
 where
     R: Read + Seek,
@@ -108,7 +270,7 @@ where
     f
 }
 
-pub fn parse_function_args_type_hint<R, Res, Args, F>(_: F, a: Args) -> Args
+pub fn parse_function_args_type_hint<R, Res, Args, F>(_: &F, a: Args) -> Args
 where
     R: Read + Seek,
     F: FnOnce(&mut R, Endian, Args) -> BinResult<Res>,
@@ -116,7 +278,7 @@ where
     a
 }
 
-pub fn write_function_args_type_hint<T, W, Args, F>(_: F, a: Args) -> Args
+pub fn write_function_args_type_hint<T, W, Args, F>(_: &F, a: Args) -> Args
 where
     W: Write + Seek,
     F: FnOnce(&T, &mut W, Endian, Args) -> BinResult<()>,
@@ -250,29 +412,85 @@ where
 }
 
 pub fn write_zeroes<W: Write>(writer: &mut W, count: u64) -> BinResult<()> {
+    write_fill(writer, 0, count)
+}
+
+pub fn write_fill<W: Write>(writer: &mut W, fill: u8, count: u64) -> BinResult<()> {
     const BUF_SIZE: u16 = 0x20;
-    const ZEROES: [u8; BUF_SIZE as usize] = [0u8; BUF_SIZE as usize];
+    let buf = [fill; BUF_SIZE as usize];
 
     if count <= BUF_SIZE.into() {
         // Lint: `count` is guaranteed to be <= BUF_SIZE
         #[allow(clippy::cast_possible_truncation)]
-        writer.write_all(&ZEROES[..count as usize])?;
+        writer.write_all(&buf[..count as usize])?;
     } else {
         let full_chunks = count / u64::from(BUF_SIZE);
         let remaining = count % u64::from(BUF_SIZE);
 
         for _ in 0..full_chunks {
-            writer.write_all(&ZEROES)?;
+            writer.write_all(&buf)?;
         }
 
         // Lint: `remaining` is guaranteed to be < BUF_SIZE
         #[allow(clippy::cast_possible_truncation)]
-        writer.write_all(&ZEROES[..remaining as usize])?;
+        writer.write_all(&buf[..remaining as usize])?;
     }
 
     Ok(())
 }
 
+pub fn write_count<W, T, C>(writer: &mut W, value: &T, count: C) -> BinResult<()>
+where
+    W: Seek,
+    T: WriteCount + ?Sized,
+    C: TryInto<usize> + core::fmt::Debug + Copy,
+{
+    let len = value.write_count();
+    if count.try_into().ok() == Some(len) {
+        Ok(())
+    } else {
+        Err(Error::AssertFail {
+            pos: writer.stream_position()?,
+            message: alloc::format!("count {count:?} does not match length {len}"),
+        })
+    }
+}
+
+// Collections which can be checked against a `count` directive when writing.
+pub trait WriteCount {
+    fn write_count(&self) -> usize;
+}
+
+impl<T: WriteCount + ?Sized> WriteCount for &T {
+    fn write_count(&self) -> usize {
+        (**self).write_count()
+    }
+}
+
+macro_rules! write_count_impl {
+    ($($(#[$meta:meta])* [$($generics:tt)*] $Ty:ty),+ $(,)?) => {$(
+        $(#[$meta])*
+        impl<$($generics)*> WriteCount for $Ty {
+            fn write_count(&self) -> usize {
+                self.len()
+            }
+        }
+    )+};
+}
+
+write_count_impl!(
+    [T] [T],
+    [T, const N: usize] [T; N],
+    [T] alloc::vec::Vec<T>,
+    [T] alloc::collections::VecDeque<T>,
+    [T] alloc::collections::BTreeSet<T>,
+    [K, V] alloc::collections::BTreeMap<K, V>,
+    #[cfg(feature = "std")]
+    [T, S] std::collections::HashSet<T, S>,
+    #[cfg(feature = "std")]
+    [K, V, S] std::collections::HashMap<K, V, S>,
+);
+
 #[cfg(feature = "std")]
 pub use std::eprintln;
 
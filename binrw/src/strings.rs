@@ -3,10 +3,13 @@
 use crate::{
     alloc::string::{FromUtf16Error, FromUtf8Error},
     io::{Read, Seek, Write},
-    BinRead, BinResult, BinWrite, Endian,
+    BinRead, BinResult, BinWrite, Endian, Error, NamedArgs,
+};
+use alloc::{format, string::String, vec, vec::Vec};
+use core::{
+    fmt::{self, Write as _},
+    marker::PhantomData,
 };
-use alloc::{string::String, vec, vec::Vec};
-use core::fmt::{self, Write as _};
 
 /// A null-terminated 8-bit string.
 ///
@@ -33,13 +36,73 @@ pub struct NullString(
     pub Vec<u8>,
 );
 
+/// Named arguments for the [`BinRead::read_options()`] implementations of
+/// [`NullString`] and [`NullWideString`].
+///
+/// By default, strings have no maximum length. Set `max_len` when reading
+/// untrusted data so that a missing null terminator does not cause the
+/// rest of the input to be read into memory.
+///
+/// # Examples
+///
+/// ```
+/// use binrw::{BinRead, NullString, io::Cursor};
+///
+/// #[derive(BinRead)]
+/// struct Names {
+///     #[br(args { max_len: Some(4) })]
+///     strict: NullString,
+///     #[br(args { max_len: Some(4), truncate: true })]
+///     lenient: NullString,
+/// }
+///
+/// let names = Names::read_le(&mut Cursor::new(b"abc\0defghi\0")).unwrap();
+/// assert_eq!(names.lenient.to_string(), "defg");
+///
+/// assert!(Names::read_le(&mut Cursor::new(b"abcde\0")).is_err());
+/// ```
+#[derive(Clone, Copy, Debug, Default, NamedArgs)]
+pub struct NullStringArgs {
+    /// The maximum number of characters to read, not including the null
+    /// terminator.
+    #[named_args(default = None)]
+    pub max_len: Option<usize>,
+
+    /// If `true`, characters after `max_len` are skipped until the null
+    /// terminator is found. Otherwise, an error is returned.
+    #[named_args(default = false)]
+    pub truncate: bool,
+}
+
+impl NullStringArgs {
+    /// Returns whether another character fits in a string which already holds
+    /// `len` characters of `unit_size` bytes each.
+    fn check_len<R: Seek>(&self, reader: &mut R, len: usize, unit_size: u64) -> BinResult<bool> {
+        match self.max_len {
+            Some(max_len) if len >= max_len => {
+                if self.truncate {
+                    Ok(false)
+                } else {
+                    // The reader is just past the character which did not fit
+                    let read_len = (len as u64 + 1) * unit_size;
+                    Err(Error::AssertFail {
+                        pos: reader.stream_position()? - read_len,
+                        message: format!("string is longer than the maximum length of {max_len}"),
+                    })
+                }
+            }
+            _ => Ok(true),
+        }
+    }
+}
+
 impl BinRead for NullString {
-    type Args<'a> = ();
+    type Args<'a> = NullStringArgs;
 
     fn read_options<R: Read + Seek>(
         reader: &mut R,
         endian: Endian,
-        (): Self::Args<'_>,
+        args: Self::Args<'_>,
     ) -> BinResult<Self> {
         let mut values = vec![];
 
@@ -48,7 +111,9 @@ impl BinRead for NullString {
             if val == 0 {
                 return Ok(Self(values));
             }
-            values.push(val);
+            if args.check_len(reader, values.len(), 1)? {
+                values.push(val);
+            }
         }
     }
 }
@@ -156,12 +221,12 @@ pub struct NullWideString(
 );
 
 impl BinRead for NullWideString {
-    type Args<'a> = ();
+    type Args<'a> = NullStringArgs;
 
     fn read_options<R: Read + Seek>(
         reader: &mut R,
         endian: Endian,
-        (): Self::Args<'_>,
+        args: Self::Args<'_>,
     ) -> BinResult<Self> {
         let mut values = vec![];
 
@@ -170,7 +235,9 @@ impl BinRead for NullWideString {
             if val == 0 {
                 return Ok(Self(values));
             }
-            values.push(val);
+            if args.check_len(reader, values.len(), 2)? {
+                values.push(val);
+            }
         }
     }
 }
@@ -245,6 +312,292 @@ impl fmt::Debug for NullWideString {
     }
 }
 
+/// A length-prefixed 8-bit string.
+///
+/// The length prefix is read and written using the integer type `L`, and
+/// counts the number of bytes in the string. It is not included in the value.
+///
+/// ```
+/// use binrw::{BinReaderExt, BinWriterExt, PascalString, io::Cursor};
+///
+/// let mut data = Cursor::new(b"\0\x05hello\x05world");
+///
+/// assert_eq!(
+///     data.read_be::<PascalString<u16>>().unwrap().to_string(),
+///     "hello"
+/// );
+///
+/// assert_eq!(
+///     data.read_be::<PascalString<u8>>().unwrap().to_string(),
+///     "world"
+/// );
+///
+/// let mut out = Cursor::new(Vec::new());
+/// out.write_le(&PascalString::<u32>::from("hi")).unwrap();
+/// assert_eq!(out.into_inner(), b"\x02\0\0\0hi");
+/// ```
+#[derive(Clone, Eq, PartialEq, Default)]
+pub struct PascalString<L = u8>(
+    /// The raw byte string.
+    pub Vec<u8>,
+    PhantomData<L>,
+);
+
+impl<L> PascalString<L> {
+    /// Creates a new string from raw bytes.
+    #[must_use]
+    pub fn new(value: Vec<u8>) -> Self {
+        Self(value, PhantomData)
+    }
+}
+
+impl<L> BinRead for PascalString<L>
+where
+    L: for<'a> BinRead<Args<'a> = ()> + TryInto<usize>,
+{
+    type Args<'a> = ();
+
+    fn read_options<R: Read + Seek>(
+        reader: &mut R,
+        endian: Endian,
+        (): Self::Args<'_>,
+    ) -> BinResult<Self> {
+        let pos = reader.stream_position()?;
+        let len = L::read_options(reader, endian, ())?
+            .try_into()
+            .map_err(|_| Error::AssertFail {
+                pos,
+                message: "length prefix out of range of usize".into(),
+            })?;
+
+        let args = crate::VecArgs {
+            count: len,
+            inner: (),
+        };
+        <Vec<u8>>::read_options(reader, endian, args).map(Self::new)
+    }
+}
+
+impl<L> BinWrite for PascalString<L>
+where
+    L: for<'a> BinWrite<Args<'a> = ()> + TryFrom<usize>,
+{
+    type Args<'a> = ();
+
+    fn write_options<W: Write + Seek>(
+        &self,
+        writer: &mut W,
+        endian: Endian,
+        args: Self::Args<'_>,
+    ) -> BinResult<()> {
+        let Ok(len) = L::try_from(self.0.len()) else {
+            return Err(Error::AssertFail {
+                pos: writer.stream_position()?,
+                message: format!(
+                    "string length {} out of range of length prefix",
+                    self.0.len()
+                ),
+            });
+        };
+        len.write_options(writer, endian, args)?;
+        self.0.write_options(writer, endian, args)?;
+
+        Ok(())
+    }
+}
+
+impl<L> From<&str> for PascalString<L> {
+    fn from(s: &str) -> Self {
+        Self::new(s.as_bytes().to_vec())
+    }
+}
+
+impl<L> From<String> for PascalString<L> {
+    fn from(s: String) -> Self {
+        Self::new(s.into_bytes())
+    }
+}
+
+impl<L> From<Vec<u8>> for PascalString<L> {
+    fn from(s: Vec<u8>) -> Self {
+        Self::new(s)
+    }
+}
+
+impl<L> From<PascalString<L>> for Vec<u8> {
+    fn from(s: PascalString<L>) -> Self {
+        s.0
+    }
+}
+
+impl<L> TryFrom<PascalString<L>> for String {
+    type Error = FromUtf8Error;
+
+    fn try_from(value: PascalString<L>) -> Result<Self, Self::Error> {
+        String::from_utf8(value.0)
+    }
+}
+
+impl<L> core::ops::Deref for PascalString<L> {
+    type Target = Vec<u8>;
+
+    fn deref(&self) -> &Self::Target {
+        &self.0
+    }
+}
+
+impl<L> core::ops::DerefMut for PascalString<L> {
+    fn deref_mut(&mut self) -> &mut Self::Target {
+        &mut self.0
+    }
+}
+
+impl<L> fmt::Debug for PascalString<L> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "PascalString(\"")?;
+        display_utf8(&self.0, f, str::escape_debug)?;
+        write!(f, "\")")
+    }
+}
+
+impl<L> fmt::Display for PascalString<L> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        display_utf8(&self.0, f, str::chars)
+    }
+}
+
+/// A fixed-size 8-bit string padded with the byte `PAD`.
+///
+/// Exactly `N` bytes are always read and written. The raw bytes, including
+/// padding, are stored in the value; [`trimmed`](Self::trimmed) and
+/// [`terminated`](Self::terminated) give views of the string without its
+/// padding.
+///
+/// ```
+/// use binrw::{BinReaderExt, BinWriterExt, FixedString, io::Cursor};
+///
+/// let mut data = Cursor::new(b"name\0\0\0\0text    ");
+///
+/// let name = data.read_be::<FixedString<8>>().unwrap();
+/// assert_eq!(name.trimmed(), b"name");
+/// assert_eq!(name.to_string(), "name");
+///
+/// let text = data.read_be::<FixedString<8, b' '>>().unwrap();
+/// assert_eq!(&text.0, b"text    ");
+/// assert_eq!(text.to_string(), "text");
+///
+/// let mut out = Cursor::new(Vec::new());
+/// out.write_be(&FixedString::<6, b'_'>::new(b"abc").unwrap()).unwrap();
+/// assert_eq!(out.into_inner(), b"abc___");
+/// ```
+#[derive(Clone, Copy, Eq, PartialEq)]
+pub struct FixedString<const N: usize, const PAD: u8 = 0>(
+    /// The raw byte string, including padding.
+    pub [u8; N],
+);
+
+impl<const N: usize, const PAD: u8> FixedString<N, PAD> {
+    /// Creates a new string from `value`, padded to `N` bytes.
+    ///
+    /// Returns `None` if `value` is longer than `N` bytes.
+    #[must_use]
+    pub fn new(value: &[u8]) -> Option<Self> {
+        let mut raw = [PAD; N];
+        raw.get_mut(..value.len())?.copy_from_slice(value);
+        Some(Self(raw))
+    }
+
+    /// Returns the string with all trailing padding bytes removed.
+    #[must_use]
+    pub fn trimmed(&self) -> &[u8] {
+        let len = self.0.iter().rposition(|&b| b != PAD).map_or(0, |i| i + 1);
+        &self.0[..len]
+    }
+
+    /// Returns the string up to, but not including, the first padding byte.
+    ///
+    /// This is useful when the unused part of a field may contain garbage
+    /// after the terminating padding byte.
+    #[must_use]
+    pub fn terminated(&self) -> &[u8] {
+        let len = self.0.iter().position(|&b| b == PAD).unwrap_or(N);
+        &self.0[..len]
+    }
+}
+
+impl<const N: usize, const PAD: u8> BinRead for FixedString<N, PAD> {
+    type Args<'a> = ();
+
+    fn read_options<R: Read + Seek>(
+        reader: &mut R,
+        endian: Endian,
+        (): Self::Args<'_>,
+    ) -> BinResult<Self> {
+        <[u8; N]>::read_options(reader, endian, ()).map(Self)
+    }
+}
+
+impl<const N: usize, const PAD: u8> BinWrite for FixedString<N, PAD> {
+    type Args<'a> = ();
+
+    fn write_options<W: Write + Seek>(
+        &self,
+        writer: &mut W,
+        endian: Endian,
+        args: Self::Args<'_>,
+    ) -> BinResult<()> {
+        self.0.write_options(writer, endian, args)
+    }
+}
+
+impl<const N: usize, const PAD: u8> Default for FixedString<N, PAD> {
+    fn default() -> Self {
+        Self([PAD; N])
+    }
+}
+
+impl<const N: usize, const PAD: u8> From<FixedString<N, PAD>> for Vec<u8> {
+    fn from(s: FixedString<N, PAD>) -> Self {
+        s.trimmed().to_vec()
+    }
+}
+
+impl<const N: usize, const PAD: u8> TryFrom<FixedString<N, PAD>> for String {
+    type Error = FromUtf8Error;
+
+    fn try_from(value: FixedString<N, PAD>) -> Result<Self, Self::Error> {
+        String::from_utf8(value.trimmed().to_vec())
+    }
+}
+
+impl<const N: usize, const PAD: u8> core::ops::Deref for FixedString<N, PAD> {
+    type Target = [u8; N];
+
+    fn deref(&self) -> &Self::Target {
+        &self.0
+    }
+}
+
+impl<const N: usize, const PAD: u8> core::ops::DerefMut for FixedString<N, PAD> {
+    fn deref_mut(&mut self) -> &mut Self::Target {
+        &mut self.0
+    }
+}
+
+impl<const N: usize, const PAD: u8> fmt::Debug for FixedString<N, PAD> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "FixedString(\"")?;
+        display_utf8(self.trimmed(), f, str::escape_debug)?;
+        write!(f, "\")")
+    }
+}
+
+impl<const N: usize, const PAD: u8> fmt::Display for FixedString<N, PAD> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        display_utf8(self.trimmed(), f, str::chars)
+    }
+}
+
 fn display_utf16<Transformer: Fn(char) -> O, O: Iterator<Item = char>>(
     input: &[u16],
     f: &mut fmt::Formatter<'_>,
@@ -0,0 +1,387 @@
+//! Type definitions for common binary timestamp formats.
+//!
+//! Each type stores the raw value exactly as it was read, so writing it back
+//! always produces the original data. Conversions to and from
+//! [`SystemTime`](std::time::SystemTime) require the `std` feature.
+
+use crate::{
+    io::{Read, Seek, Write},
+    BinRead, BinResult, BinWrite, Endian,
+};
+use core::time::Duration;
+#[cfg(feature = "std")]
+use std::time::{SystemTime, UNIX_EPOCH};
+
+/// A 32-bit Unix timestamp, counting seconds since 1970-01-01 00:00:00 UTC.
+///
+/// # Examples
+///
+/// ```
+/// # #[cfg(not(feature = "std"))] fn main() {}
+/// # #[cfg(feature = "std")]
+/// # fn main() {
+/// use binrw::{BinReaderExt, io::Cursor, time::UnixTime32};
+/// use std::time::{Duration, UNIX_EPOCH};
+///
+/// let time: UnixTime32 = Cursor::new(b"\x00\x00\x01\x00").read_le().unwrap();
+/// assert_eq!(time.to_system_time(), UNIX_EPOCH + Duration::from_secs(0x10000));
+/// # }
+/// ```
+#[derive(Clone, Copy, Debug, Default, Eq, PartialEq, Ord, PartialOrd, Hash)]
+pub struct UnixTime32(
+    /// The raw number of seconds.
+    pub u32,
+);
+
+impl UnixTime32 {
+    /// Converts the timestamp to a [`SystemTime`].
+    #[cfg(feature = "std")]
+    #[must_use]
+    pub fn to_system_time(self) -> SystemTime {
+        UNIX_EPOCH + Duration::from_secs(self.0.into())
+    }
+
+    /// Converts a [`SystemTime`] to a timestamp, discarding any fractional
+    /// seconds.
+    ///
+    /// Returns `None` if the time is before 1970 or after 2106.
+    #[cfg(feature = "std")]
+    #[must_use]
+    pub fn from_system_time(time: SystemTime) -> Option<Self> {
+        let secs = time.duration_since(UNIX_EPOCH).ok()?.as_secs();
+        secs.try_into().ok().map(Self)
+    }
+}
+
+impl BinRead for UnixTime32 {
+    type Args<'a> = ();
+
+    fn read_options<R: Read + Seek>(
+        reader: &mut R,
+        endian: Endian,
+        (): Self::Args<'_>,
+    ) -> BinResult<Self> {
+        <u32>::read_options(reader, endian, ()).map(Self)
+    }
+}
+
+impl BinWrite for UnixTime32 {
+    type Args<'a> = ();
+
+    fn write_options<W: Write + Seek>(
+        &self,
+        writer: &mut W,
+        endian: Endian,
+        (): Self::Args<'_>,
+    ) -> BinResult<()> {
+        self.0.write_options(writer, endian, ())
+    }
+}
+
+/// A Windows `FILETIME`, counting 100-nanosecond intervals since
+/// 1601-01-01 00:00:00 UTC.
+///
+/// # Examples
+///
+/// ```
+/// # #[cfg(not(feature = "std"))] fn main() {}
+/// # #[cfg(feature = "std")]
+/// # fn main() {
+/// use binrw::{BinReaderExt, io::Cursor, time::FileTime};
+/// use std::time::UNIX_EPOCH;
+///
+/// let time: FileTime = Cursor::new(b"\x00\x80\x3e\xd5\xde\xb1\x9d\x01").read_le().unwrap();
+/// assert_eq!(time.to_system_time(), Some(UNIX_EPOCH));
+/// # }
+/// ```
+#[derive(Clone, Copy, Debug, Default, Eq, PartialEq, Ord, PartialOrd, Hash)]
+pub struct FileTime(
+    /// The raw number of 100-nanosecond intervals.
+    pub u64,
+);
+
+impl FileTime {
+    const TICKS_PER_SEC: u64 = 10_000_000;
+    #[cfg(feature = "std")]
+    const UNIX_EPOCH_OFFSET: Duration = Duration::from_secs(11_644_473_600);
+
+    /// Returns the time elapsed since 1601-01-01 00:00:00 UTC.
+    #[must_use]
+    pub fn to_duration(self) -> Duration {
+        let nanos = (self.0 % Self::TICKS_PER_SEC) * 100;
+        // Lint: `nanos` is always less than one billion
+        #[allow(clippy::cast_possible_truncation)]
+        Duration::new(self.0 / Self::TICKS_PER_SEC, nanos as u32)
+    }
+
+    /// Converts a duration since 1601-01-01 00:00:00 UTC to a timestamp,
+    /// discarding any precision finer than 100 nanoseconds.
+    ///
+    /// Returns `None` if the duration is too large to represent.
+    #[must_use]
+    pub fn from_duration(duration: Duration) -> Option<Self> {
+        duration
+            .as_secs()
+            .checked_mul(Self::TICKS_PER_SEC)?
+            .checked_add(u64::from(duration.subsec_nanos() / 100))
+            .map(Self)
+    }
+
+    /// Converts the timestamp to a [`SystemTime`].
+    ///
+    /// Returns `None` if the time cannot be represented by the platform.
+    #[cfg(feature = "std")]
+    #[must_use]
+    pub fn to_system_time(self) -> Option<SystemTime> {
+        let since_1601 = self.to_duration();
+        match since_1601.checked_sub(Self::UNIX_EPOCH_OFFSET) {
+            Some(since_unix) => UNIX_EPOCH.checked_add(since_unix),
+            None => UNIX_EPOCH.checked_sub(Self::UNIX_EPOCH_OFFSET.checked_sub(since_1601)?),
+        }
+    }
+
+    /// Converts a [`SystemTime`] to a timestamp, discarding any precision
+    /// finer than 100 nanoseconds.
+    ///
+    /// Returns `None` if the time is before 1601 or too far in the future to
+    /// represent.
+    #[cfg(feature = "std")]
+    #[must_use]
+    pub fn from_system_time(time: SystemTime) -> Option<Self> {
+        let since_1601 = match time.duration_since(UNIX_EPOCH) {
+            Ok(since_unix) => since_unix.checked_add(Self::UNIX_EPOCH_OFFSET)?,
+            Err(before_unix) => Self::UNIX_EPOCH_OFFSET.checked_sub(before_unix.duration())?,
+        };
+        Self::from_duration(since_1601)
+    }
+}
+
+impl BinRead for FileTime {
+    type Args<'a> = ();
+
+    fn read_options<R: Read + Seek>(
+        reader: &mut R,
+        endian: Endian,
+        (): Self::Args<'_>,
+    ) -> BinResult<Self> {
+        <u64>::read_options(reader, endian, ()).map(Self)
+    }
+}
+
+impl BinWrite for FileTime {
+    type Args<'a> = ();
+
+    fn write_options<W: Write + Seek>(
+        &self,
+        writer: &mut W,
+        endian: Endian,
+        (): Self::Args<'_>,
+    ) -> BinResult<()> {
+        self.0.write_options(writer, endian, ())
+    }
+}
+
+/// An MS-DOS date and time, as used by FAT file systems and ZIP archives.
+///
+/// The time is stored first, followed by the date. DOS timestamps have no
+/// time zone and a resolution of two seconds.
+///
+/// # Examples
+///
+/// ```
+/// use binrw::{BinReaderExt, io::Cursor, time::DosDateTime};
+///
+/// let time: DosDateTime = Cursor::new(b"\x2f\x7b\x21\x5a").read_le().unwrap();
+/// assert_eq!(time.year(), 2025);
+/// assert_eq!((time.month(), time.day()), (1, 1));
+/// assert_eq!((time.hour(), time.minute(), time.second()), (15, 25, 30));
+/// ```
+#[derive(Clone, Copy, Debug, Default, Eq, PartialEq, Hash)]
+pub struct DosDateTime {
+    /// The raw time, packed as `hhhhhmmmmmmsssss` with seconds divided by two.
+    pub time: u16,
+    /// The raw date, packed as `yyyyyyymmmmddddd` with years since 1980.
+    pub date: u16,
+}
+
+impl DosDateTime {
+    /// Creates a new timestamp from its components.
+    ///
+    /// Odd seconds are rounded down, since DOS timestamps have a resolution of
+    /// two seconds.
+    ///
+    /// Returns `None` if any component is out of range, including days past
+    /// the end of the month. Years from 1980 to 2107 can be represented.
+    #[must_use]
+    pub fn new(year: u16, month: u8, day: u8, hour: u8, minute: u8, second: u8) -> Option<Self> {
+        let dos_year = year.checked_sub(1980).filter(|&year| year < 128)?;
+        if !(1..=12).contains(&month)
+            || !(1..=days_in_month(year, month)).contains(&day)
+            || hour > 23
+            || minute > 59
+            || second > 59
+        {
+            return None;
+        }
+
+        Some(Self {
+            time: (u16::from(hour) << 11) | (u16::from(minute) << 5) | u16::from(second / 2),
+            date: (dos_year << 9) | (u16::from(month) << 5) | u16::from(day),
+        })
+    }
+
+    /// Returns the year.
+    #[must_use]
+    pub fn year(self) -> u16 {
+        1980 + (self.date >> 9)
+    }
+
+    /// Returns the month, where January is 1.
+    #[must_use]
+    pub fn month(self) -> u8 {
+        ((self.date >> 5) & 0xf) as u8
+    }
+
+    /// Returns the day of the month, starting from 1.
+    #[must_use]
+    pub fn day(self) -> u8 {
+        (self.date & 0x1f) as u8
+    }
+
+    /// Returns the hour.
+    #[must_use]
+    pub fn hour(self) -> u8 {
+        (self.time >> 11) as u8
+    }
+
+    /// Returns the minute.
+    #[must_use]
+    pub fn minute(self) -> u8 {
+        ((self.time >> 5) & 0x3f) as u8
+    }
+
+    /// Returns the second.
+    ///
+    /// The raw value is not validated, so this may return up to 62.
+    #[must_use]
+    pub fn second(self) -> u8 {
+        (self.time & 0x1f) as u8 * 2
+    }
+
+    /// Converts the timestamp to a [`SystemTime`], treating it as UTC.
+    ///
+    /// Returns `None` if any component is out of range.
+    #[cfg(feature = "std")]
+    #[must_use]
+    pub fn to_system_time(self) -> Option<SystemTime> {
+        let this = Self::new(
+            self.year(),
+            self.month(),
+            self.day(),
+            self.hour(),
+            self.minute(),
+            self.second(),
+        )?;
+
+        let days = days_from_civil(this.year().into(), this.month().into(), this.day().into());
+        let secs = days * 86_400
+            + u64::from(this.hour()) * 3600
+            + u64::from(this.minute()) * 60
+            + u64::from(this.second());
+        Some(UNIX_EPOCH + Duration::from_secs(secs))
+    }
+
+    /// Converts a [`SystemTime`] to a timestamp in UTC, rounding down to an
+    /// even second.
+    ///
+    /// Returns `None` if the time is before 1980 or after 2107.
+    #[cfg(feature = "std")]
+    #[must_use]
+    pub fn from_system_time(time: SystemTime) -> Option<Self> {
+        let secs = time.duration_since(UNIX_EPOCH).ok()?.as_secs();
+        let (year, month, day) = civil_from_days(secs / 86_400);
+        let secs = secs % 86_400;
+        // Lint: All components are within the range of their types
+        #[allow(clippy::cast_possible_truncation)]
+        Self::new(
+            year.try_into().ok()?,
+            month as u8,
+            day as u8,
+            (secs / 3600) as u8,
+            (secs / 60 % 60) as u8,
+            (secs % 60) as u8,
+        )
+    }
+}
+
+impl BinRead for DosDateTime {
+    type Args<'a> = ();
+
+    fn read_options<R: Read + Seek>(
+        reader: &mut R,
+        endian: Endian,
+        (): Self::Args<'_>,
+    ) -> BinResult<Self> {
+        Ok(Self {
+            time: <u16>::read_options(reader, endian, ())?,
+            date: <u16>::read_options(reader, endian, ())?,
+        })
+    }
+}
+
+impl BinWrite for DosDateTime {
+    type Args<'a> = ();
+
+    fn write_options<W: Write + Seek>(
+        &self,
+        writer: &mut W,
+        endian: Endian,
+        (): Self::Args<'_>,
+    ) -> BinResult<()> {
+        self.time.write_options(writer, endian, ())?;
+        self.date.write_options(writer, endian, ())
+    }
+}
+
+// The number of days in a month of the Gregorian calendar
+fn days_in_month(year: u16, month: u8) -> u8 {
+    match month {
+        2 if year % 4 == 0 && (year % 100 != 0 || year % 400 == 0) => 29,
+        2 => 28,
+        4 | 6 | 9 | 11 => 30,
+        _ => 31,
+    }
+}
+
+// Days since 1970-01-01 of a proleptic Gregorian date from 1970 onwards, from
+// <https://howardhinnant.github.io/date_algorithms.html#days_from_civil>
+#[cfg(feature = "std")]
+fn days_from_civil(year: u64, month: u64, day: u64) -> u64 {
+    let year = if month <= 2 { year - 1 } else { year };
+    let era = year / 400;
+    let year_of_era = year - era * 400;
+    let day_of_year = (153 * ((month + 9) % 12) + 2) / 5 + day - 1;
+    let day_of_era = year_of_era * 365 + year_of_era / 4 - year_of_era / 100 + day_of_year;
+    era * 146_097 + day_of_era - 719_468
+}
+
+// The inverse of `days_from_civil`, from
+// <https://howardhinnant.github.io/date_algorithms.html#civil_from_days>
+#[cfg(feature = "std")]
+fn civil_from_days(days: u64) -> (u64, u64, u64) {
+    let days = days + 719_468;
+    let era = days / 146_097;
+    let day_of_era = days - era * 146_097;
+    let year_of_era =
+        (day_of_era - day_of_era / 1460 + day_of_era / 36_524 - day_of_era / 146_096) / 365;
+    let day_of_year = day_of_era - (365 * year_of_era + year_of_era / 4 - year_of_era / 100);
+    let month_from_march = (5 * day_of_year + 2) / 153;
+    let day = day_of_year - (153 * month_from_march + 2) / 5 + 1;
+    let month = if month_from_march < 10 {
+        month_from_march + 3
+    } else {
+        month_from_march - 9
+    };
+    let year = year_of_era + era * 400 + u64::from(month <= 2);
+    (year, month, day)
+}
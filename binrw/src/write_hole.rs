@@ -0,0 +1,147 @@
+use crate::{
+    io::{Seek, SeekFrom, Write},
+    BinResult, BinWrite, Endian, Error,
+};
+use core::{fmt, marker::PhantomData};
+
+/// A reserved region of a writer that is filled in after the data following
+/// it has been written.
+///
+/// This is useful for formats that store a size or offset before the data it
+/// describes, when the value is not known until the data is written. A
+/// placeholder value is written when the hole is created, and the real value
+/// is written over it by [`fill()`](Self::fill).
+///
+/// The placeholder and the final value must be written using the same number
+/// of bytes. If they are not, [`fill()`](Self::fill) returns an error, and
+/// the data following the hole may have been overwritten.
+///
+/// # Examples
+///
+/// ```
+/// use binrw::{prelude::*, io::{Cursor, Seek, Write}, Endian, WriteHole};
+///
+/// struct Chunk {
+///     data: Vec<u8>,
+/// }
+///
+/// impl BinWrite for Chunk {
+///     type Args<'a> = ();
+///
+///     fn write_options<W: Write + Seek>(
+///         &self,
+///         writer: &mut W,
+///         endian: Endian,
+///         args: Self::Args<'_>,
+///     ) -> BinResult<()> {
+///         let size = WriteHole::<u32>::reserve(writer, endian, ())?;
+///         let start = writer.stream_position()?;
+///         self.data.write_options(writer, endian, ())?;
+///         let end = writer.stream_position()?;
+///         size.fill(writer, &((end - start) as u32), ())
+///     }
+/// }
+///
+/// let mut out = Cursor::new(Vec::new());
+/// Chunk { data: vec![1, 2, 3] }.write_be(&mut out).unwrap();
+/// assert_eq!(out.into_inner(), b"\0\0\0\x03\x01\x02\x03");
+/// ```
+pub struct WriteHole<T> {
+    pos: u64,
+    len: u64,
+    endian: Endian,
+    phantom: PhantomData<fn(&T)>,
+}
+
+impl<T: BinWrite> WriteHole<T> {
+    /// Writes `placeholder` at the current position of the writer and
+    /// returns a hole which can later be filled with the real value.
+    ///
+    /// # Errors
+    ///
+    /// If writing fails, an [`Error`](crate::Error) variant will be returned.
+    pub fn new<W: Write + Seek>(
+        writer: &mut W,
+        endian: Endian,
+        placeholder: &T,
+        args: T::Args<'_>,
+    ) -> BinResult<Self> {
+        let pos = writer.stream_position()?;
+        placeholder.write_options(writer, endian, args)?;
+        let len = writer.stream_position()?.saturating_sub(pos);
+        Ok(Self {
+            pos,
+            len,
+            endian,
+            phantom: PhantomData,
+        })
+    }
+
+    /// Writes the default value of `T` at the current position of the writer
+    /// and returns a hole which can later be filled with the real value.
+    ///
+    /// # Errors
+    ///
+    /// If writing fails, an [`Error`](crate::Error) variant will be returned.
+    pub fn reserve<W: Write + Seek>(
+        writer: &mut W,
+        endian: Endian,
+        args: T::Args<'_>,
+    ) -> BinResult<Self>
+    where
+        T: Default,
+    {
+        Self::new(writer, endian, &T::default(), args)
+    }
+
+    /// Writes `value` over the placeholder using the endianness the hole was
+    /// created with, then returns the writer to its previous position.
+    ///
+    /// # Errors
+    ///
+    /// If `value` is not written using the same number of bytes as the
+    /// placeholder, an [`AssertFail`](crate::Error::AssertFail) error will be
+    /// returned. If writing or seeking fails, an [`Error`](crate::Error)
+    /// variant will be returned.
+    pub fn fill<W: Write + Seek>(
+        self,
+        writer: &mut W,
+        value: &T,
+        args: T::Args<'_>,
+    ) -> BinResult<()> {
+        let end = writer.stream_position()?;
+        writer.seek(SeekFrom::Start(self.pos))?;
+        value.write_options(writer, self.endian, args)?;
+        let len = writer.stream_position()?.checked_sub(self.pos);
+        writer.seek(SeekFrom::Start(end))?;
+        if len == Some(self.len) {
+            Ok(())
+        } else {
+            Err(Error::AssertFail {
+                pos: self.pos,
+                message: alloc::format!(
+                    "value did not fill the {} byte placeholder exactly",
+                    self.len
+                ),
+            })
+        }
+    }
+}
+
+impl<T> WriteHole<T> {
+    /// The byte position of the start of the hole.
+    #[must_use]
+    pub fn pos(&self) -> u64 {
+        self.pos
+    }
+}
+
+impl<T> fmt::Debug for WriteHole<T> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("WriteHole")
+            .field("pos", &self.pos)
+            .field("len", &self.len)
+            .field("endian", &self.endian)
+            .finish()
+    }
+}
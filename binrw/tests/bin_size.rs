@@ -0,0 +1,90 @@
+use binrw::{binwrite, io::Cursor, BinSize, BinWrite, BinWriterExt, Endian, NullString};
+
+#[test]
+fn primitives() {
+    assert_eq!(0u8.bin_size(Endian::Little).unwrap(), 1);
+    assert_eq!(0u32.bin_size(Endian::Big).unwrap(), 4);
+    assert_eq!([0u16; 5].bin_size(Endian::Little).unwrap(), 10);
+    assert_eq!(vec![0u64; 3].bin_size(Endian::Little).unwrap(), 24);
+    assert_eq!(NullString::from("abc").bin_size(Endian::Little).unwrap(), 4);
+    assert_eq!(().bin_size(Endian::Little).unwrap(), 0);
+}
+
+#[test]
+fn derived() {
+    #[binwrite]
+    #[bw(big, magic = b"HDR")]
+    struct Test {
+        #[bw(calc = items.len() as u8)]
+        len: u8,
+        #[bw(pad_before = 2)]
+        items: Vec<u16>,
+        #[bw(if(*flag != 0), align_before = 8)]
+        extra: Option<u32>,
+        #[bw(pad_size_to = 4)]
+        flag: u8,
+        name: NullString,
+    }
+
+    for value in [
+        Test {
+            items: vec![1, 2, 3],
+            extra: None,
+            flag: 0,
+            name: "x".into(),
+        },
+        Test {
+            items: vec![1],
+            extra: Some(5),
+            flag: 1,
+            name: "hello".into(),
+        },
+    ] {
+        let mut out = Cursor::new(Vec::new());
+        out.write_be(&value).unwrap();
+        assert_eq!(
+            value.bin_size(Endian::Big).unwrap(),
+            out.into_inner().len() as u64
+        );
+    }
+}
+
+#[test]
+fn args() {
+    #[binwrite]
+    #[bw(import(len: u64))]
+    struct Test {
+        #[bw(pad_after = len)]
+        value: u8,
+    }
+
+    assert_eq!(
+        Test { value: 0 }
+            .bin_size_args(Endian::Little, (7,))
+            .unwrap(),
+        8
+    );
+}
+
+#[test]
+fn seek_back() {
+    struct Test;
+
+    impl BinWrite for Test {
+        type Args<'a> = ();
+
+        fn write_options<W: binrw::io::Write + binrw::io::Seek>(
+            &self,
+            writer: &mut W,
+            endian: Endian,
+            (): Self::Args<'_>,
+        ) -> binrw::BinResult<()> {
+            0u32.write_options(writer, endian, ())?;
+            writer.seek(binrw::io::SeekFrom::Start(8))?;
+            writer.seek(binrw::io::SeekFrom::Current(-6))?;
+            1u8.write_options(writer, endian, ())
+        }
+    }
+
+    assert_eq!(Test.bin_size(Endian::Little).unwrap(), 4);
+}
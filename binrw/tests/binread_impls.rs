@@ -1,4 +1,18 @@
-use binrw::{io::Cursor, BinRead};
+use binrw::{io::Cursor, BinRead, BinWrite};
+
+#[test]
+fn array() {
+    let data = (0..=255).cycle().take(4096).collect::<Vec<u8>>();
+    let array = <[u8; 4096]>::read(&mut Cursor::new(&data)).unwrap();
+    assert_eq!(&array[..], &data[..]);
+
+    let array = <[u16; 1000]>::read_le(&mut Cursor::new(&data)).unwrap();
+    assert_eq!(array[999], 0xcfce);
+
+    assert!(<[u8; 4097]>::read(&mut Cursor::new(&data))
+        .unwrap_err()
+        .is_eof());
+}
 
 #[test]
 fn boxed() {
@@ -11,6 +25,78 @@ fn boxed() {
         .is_eof());
 }
 
+#[test]
+fn cached_file_ptr() {
+    use binrw::{file_ptr::FilePtrCache, FilePtr8};
+    use std::rc::Rc;
+
+    #[derive(BinRead)]
+    #[br(import(cache: &FilePtrCache<u16>))]
+    struct Record {
+        #[br(parse_with = FilePtr8::parse_cached, args { cache, offset: 1 })]
+        value: Rc<u16>,
+    }
+
+    let cache = FilePtrCache::new();
+    let mut data = Cursor::new(b"\x04\x06\x04\0\x12\x34\x56\x78\x9a");
+    let records =
+        Vec::<Record>::read_be_args(&mut data, binrw::args! { count: 3, inner: (&cache,) })
+            .unwrap();
+    assert_eq!(data.position(), 3);
+    assert_eq!(*records[0].value, 0x3456);
+    assert_eq!(*records[1].value, 0x789a);
+    assert!(Rc::ptr_eq(&records[0].value, &records[2].value));
+    assert_eq!(cache.len(), 2);
+
+    data.set_position(0);
+    let record = Record::read_le_args(&mut data, (&cache,)).unwrap();
+    assert_eq!(*record.value, 0x5634);
+    assert_eq!(cache.len(), 3);
+
+    cache.clear();
+    assert!(cache.is_empty());
+}
+
+#[cfg(feature = "std")]
+#[test]
+fn collections() {
+    use binrw::{args, BinReaderExt};
+    use std::collections::{BTreeMap, BTreeSet, HashMap, HashSet, VecDeque};
+
+    #[derive(BinRead, Debug, PartialEq)]
+    #[br(little)]
+    struct Test {
+        len: u32,
+        #[br(count = len)]
+        map: HashMap<u8, u16>,
+        #[br(count = len)]
+        set: HashSet<u8>,
+        #[br(count = len)]
+        btree_map: BTreeMap<u8, u16>,
+        #[br(count = len)]
+        btree_set: BTreeSet<u8>,
+        #[br(count = len)]
+        deque: VecDeque<u8>,
+    }
+
+    let test: Test =
+        Cursor::new(b"\x02\0\0\0\x01\x0a\0\x01\x0b\0\x05\x06\x03\x0c\0\x04\x0d\0\x07\x07\x08\x09")
+            .read_le()
+            .unwrap();
+    assert_eq!(test.len, 2);
+    assert_eq!(test.map, HashMap::from([(1, 11)]));
+    assert_eq!(test.set, HashSet::from([5, 6]));
+    assert_eq!(test.btree_map, BTreeMap::from([(3, 12), (4, 13)]));
+    assert_eq!(test.btree_set, BTreeSet::from([7]));
+    assert_eq!(test.deque, VecDeque::from([8, 9]));
+
+    assert!(
+        BTreeSet::<u16>::read_le_args(&mut Cursor::new(b"\x01\0\x02"), args! { count: 2 })
+            .unwrap_err()
+            .is_eof()
+    );
+}
+
 #[test]
 fn convenience_endian() {
     #[derive(BinRead, Debug, Eq, PartialEq)]
@@ -75,23 +161,181 @@ fn clone_args() {
     TestCloneArray::read_le(&mut Cursor::new(b"")).unwrap();
 }
 
+#[test]
+fn lazy_file_ptr() {
+    use binrw::{
+        file_ptr::{FilePtrArgs, LazyFilePtr},
+        BinReaderExt,
+    };
+
+    #[derive(BinRead)]
+    #[br(big)]
+    struct Test {
+        a: LazyFilePtr<u8, u16>,
+        #[br(offset = 1)]
+        b: LazyFilePtr<u8, u8>,
+        missing: LazyFilePtr<u8, u32>,
+        tail: u8,
+    }
+
+    let mut data = Cursor::new(b"\x04\x05\x10\xff\x12\x34");
+    let mut test = Test::read(&mut data).unwrap();
+    assert_eq!(data.position(), 4);
+    assert_eq!(test.tail, 0xff);
+    assert_eq!((test.a.ptr, test.a.pos()), (4, 4));
+    assert_eq!(test.b.pos(), 6);
+
+    assert_eq!(*test.a.get(&mut data).unwrap(), 0x1234);
+    assert_eq!(data.position(), 4);
+    // The cached value is returned without reading again
+    assert_eq!(*test.a.get(&mut Cursor::new(b"")).unwrap(), 0x1234);
+    assert_eq!(test.a.into_inner(), Some(0x1234));
+
+    assert!(test.b.get(&mut data).unwrap_err().is_eof());
+    assert!(test.b.cached().is_none());
+    assert!(test.missing.get(&mut data).is_err());
+    assert_eq!(data.position(), 4);
+
+    let mut ptr = Cursor::new(b"\x02\0")
+        .read_le_args::<LazyFilePtr<u16, Vec<u8>>>(FilePtrArgs::default())
+        .unwrap();
+    assert_eq!(
+        ptr.get_args(
+            &mut Cursor::new(b"\0\0\x01\x02\x03"),
+            binrw::args! { count: 2 }
+        )
+        .unwrap(),
+        &[1, 2]
+    );
+}
+
 #[test]
 fn non_zero() {
-    assert!(matches!(
-        core::num::NonZeroU8::read(&mut Cursor::new(b"\0")).expect_err("accepted bad data"),
-        binrw::Error::Io(..)
-    ));
+    let err = core::num::NonZeroU8::read(&mut Cursor::new(b"\0")).expect_err("accepted bad data");
+    assert!(matches!(err, binrw::Error::Io(..)));
+    #[cfg(feature = "std")]
+    assert!(format!("{err}").contains("NonZeroU8"));
     assert_eq!(
         core::num::NonZeroU8::read(&mut Cursor::new(b"\x01")).unwrap(),
         core::num::NonZeroU8::new(1).unwrap()
     );
 }
 
+#[test]
+fn non_zero_option() {
+    use core::num::NonZeroU16;
+
+    #[derive(BinRead, BinWrite, Debug, PartialEq)]
+    #[brw(little)]
+    struct Test {
+        #[br(map = NonZeroU16::new)]
+        #[bw(map = |x| x.map_or(0, NonZeroU16::get))]
+        value: Option<NonZeroU16>,
+    }
+
+    for (data, value) in [(b"\0\0", None), (b"\x02\0", NonZeroU16::new(2))] {
+        let test = Test::read(&mut Cursor::new(data)).unwrap();
+        assert_eq!(test, Test { value });
+
+        let mut out = Cursor::new(Vec::new());
+        test.write(&mut out).unwrap();
+        assert_eq!(out.into_inner(), data);
+    }
+}
+
+#[cfg(feature = "std")]
+#[test]
+fn net() {
+    use binrw::{BinReaderExt, SocketAddrArgs};
+    use std::net::{Ipv4Addr, Ipv6Addr, SocketAddr, SocketAddrV4};
+
+    let mut data = Cursor::new(b"\xc0\xa8\0\x01\xc0\xa8\0\x01\x1f\x90");
+    assert_eq!(
+        data.read_le::<Ipv4Addr>().unwrap(),
+        Ipv4Addr::new(192, 168, 0, 1)
+    );
+    assert_eq!(
+        data.read_le::<SocketAddrV4>().unwrap(),
+        SocketAddrV4::new(Ipv4Addr::new(192, 168, 0, 1), 0x901f)
+    );
+
+    let mut data = Cursor::new(b"\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\x01\x01\xbb");
+    assert_eq!(
+        data.read_be_args::<SocketAddr>(SocketAddrArgs { is_v6: true })
+            .unwrap(),
+        SocketAddr::new(Ipv6Addr::LOCALHOST.into(), 443)
+    );
+    data.set_position(0);
+    assert_eq!(data.read_be::<Ipv6Addr>().unwrap(), Ipv6Addr::LOCALHOST);
+
+    assert!(Ipv4Addr::read_le(&mut Cursor::new(b"\x7f\0\0"))
+        .unwrap_err()
+        .is_eof());
+}
+
 #[test]
 fn phantom_data() {
     core::marker::PhantomData::<()>::read(&mut Cursor::new(b"")).unwrap();
 }
 
+#[test]
+fn read_into() {
+    use binrw::{args, BinReaderExt, Endian};
+
+    let mut data = Cursor::new(b"\x01\x02\x03\x04\x05\x06\x07");
+    let mut bytes = Vec::with_capacity(16);
+    data.read_type_args_into::<Vec<u8>>(Endian::Big, args! { count: 2 }, &mut bytes)
+        .unwrap();
+    assert_eq!(bytes, [1, 2]);
+    data.read_type_args_into::<Vec<u8>>(Endian::Big, args! { count: 3 }, &mut bytes)
+        .unwrap();
+    assert_eq!(bytes, [3, 4, 5]);
+    assert!(bytes.capacity() >= 16);
+    assert!(data
+        .read_type_args_into::<Vec<u8>>(Endian::Big, args! { count: 3 }, &mut bytes)
+        .unwrap_err()
+        .is_eof());
+
+    data.set_position(0);
+    let mut words = Box::new(Vec::<u16>::new());
+    data.read_type_args_into::<Box<Vec<u16>>>(Endian::Little, args! { count: 2 }, &mut words)
+        .unwrap();
+    assert_eq!(*words, [0x201, 0x403]);
+
+    data.set_position(0);
+    let mut dwords = vec![0_u32; 4];
+    data.read_type_args_into::<Vec<u32>>(Endian::Big, args! { count: 1 }, &mut dwords)
+        .unwrap();
+    assert_eq!(dwords, [0x0102_0304]);
+    assert!(dwords.capacity() >= 4);
+
+    data.set_position(4);
+    let mut value = 0_u16;
+    data.read_type_into(Endian::Big, &mut value).unwrap();
+    assert_eq!(value, 0x506);
+}
+
+#[test]
+fn smart_pointers() {
+    use binrw::{args, BinReaderExt};
+    use std::{borrow::Cow, rc::Rc, sync::Arc};
+
+    assert_eq!(
+        Cursor::new(b"\0\x03").read_be::<Rc<u16>>().unwrap(),
+        Rc::new(3)
+    );
+    assert_eq!(
+        Cursor::new(b"\x03\0").read_le::<Arc<u16>>().unwrap(),
+        Arc::new(3)
+    );
+
+    let cow = Cursor::new(b"\x01\x02\x03")
+        .read_be_args::<Cow<'_, [u8]>>(args! { count: 2 })
+        .unwrap();
+    assert!(matches!(cow, Cow::Owned(_)));
+    assert_eq!(&*cow, &[1, 2]);
+}
+
 #[test]
 fn tuple() {
     assert_eq!(
@@ -60,6 +60,80 @@ fn clone_args() {
     );
 }
 
+#[test]
+fn file_ptr() {
+    use binrw::{args, binrw, io::Cursor, BinRead, FilePtr16, FilePtr8};
+
+    let mut output = Cursor::new(vec![]);
+    FilePtr16 {
+        ptr: 3,
+        value: 0x102_u16,
+    }
+    .write_be(&mut output)
+    .unwrap();
+    assert_eq!(output.into_inner(), b"\0\x03\0\x01\x02");
+
+    let mut output = Cursor::new(vec![]);
+    FilePtr8 {
+        ptr: 1,
+        value: 5_u8,
+    }
+    .write_le_args(&mut output, args! { offset: 2 })
+    .unwrap();
+    assert_eq!(output.into_inner(), b"\x01\0\0\x05");
+
+    #[binrw]
+    #[derive(Debug, PartialEq)]
+    #[brw(big)]
+    struct Test {
+        a: FilePtr8<u16>,
+        b: FilePtr8<u8>,
+        c: u8,
+    }
+
+    let data = b"\x05\x04\xff\0\x07\x01\x02";
+    let value = Test::read(&mut Cursor::new(data)).unwrap();
+    assert_eq!(*value.a, 0x102);
+    assert_eq!(*value.b, 7);
+    let mut output = Cursor::new(vec![]);
+    value.write(&mut output).unwrap();
+    assert_eq!(output.into_inner(), data);
+}
+
+#[test]
+fn nullable_file_ptr() {
+    use binrw::{binrw, file_ptr::NullableFilePtr8, io::Cursor, BinRead};
+
+    #[binrw]
+    #[derive(Debug, PartialEq)]
+    #[brw(big)]
+    struct Test {
+        a: NullableFilePtr8<u16>,
+        #[br(offset = 2)]
+        b: NullableFilePtr8<u8>,
+        c: u8,
+    }
+
+    let data = b"\x03\0\xff\x01\x02";
+    let value = Test::read(&mut Cursor::new(data)).unwrap();
+    assert_eq!(*value.a, Some(0x102));
+    assert_eq!(*value.b, None);
+    assert_eq!(value.c, 0xff);
+    let mut output = Cursor::new(vec![]);
+    value.write(&mut output).unwrap();
+    assert_eq!(output.into_inner(), data);
+
+    for (ptr, value) in [(0, Some(1)), (1, None)] {
+        let mut output = Cursor::new(vec![0xff]);
+        output.set_position(1);
+        let error = NullableFilePtr8::<u8> { ptr, value }
+            .write_le(&mut output)
+            .unwrap_err();
+        assert!(matches!(error, binrw::Error::AssertFail { pos: 1, .. }));
+        assert_eq!(output.into_inner(), b"\xff");
+    }
+}
+
 #[test]
 fn non_zero() {
     compare!(core::num::NonZeroU8::new(1).unwrap(), b"\x01");
@@ -75,6 +149,70 @@ fn non_zero() {
     );
 }
 
+#[cfg(feature = "std")]
+#[test]
+fn collections() {
+    use std::collections::{BTreeMap, BTreeSet, HashMap, HashSet, VecDeque};
+
+    #[binrw::binwrite]
+    #[bw(little)]
+    struct Test {
+        #[bw(try_calc = u32::try_from(deque.len()))]
+        len: u32,
+        #[bw(count = len)]
+        deque: VecDeque<u16>,
+        #[bw(count = len)]
+        btree_set: BTreeSet<u8>,
+        #[bw(count = len)]
+        btree_map: BTreeMap<u8, u16>,
+        map: HashMap<u8, u16>,
+        set: HashSet<u8>,
+    }
+
+    compare!(
+        Test {
+            deque: VecDeque::from([8, 9]),
+            btree_set: BTreeSet::from([7, 6]),
+            btree_map: BTreeMap::from([(4, 13), (3, 12)]),
+            map: HashMap::from([(1, 11)]),
+            set: HashSet::from([5]),
+        },
+        b"\x02\0\0\0\x08\0\x09\0\x06\x07\x03\x0c\0\x04\x0d\0\x01\x0b\0\x05"
+    );
+}
+
+#[cfg(feature = "std")]
+#[test]
+fn net() {
+    use std::net::{Ipv4Addr, Ipv6Addr, SocketAddr, SocketAddrV6};
+
+    compare!(
+        Ipv4Addr::new(192, 168, 0, 1),
+        Endian::Little,
+        b"\xc0\xa8\0\x01"
+    );
+    compare!(
+        SocketAddr::new(Ipv4Addr::LOCALHOST.into(), 8080),
+        Endian::Big,
+        b"\x7f\0\0\x01\x1f\x90"
+    );
+    compare!(
+        SocketAddr::new(Ipv4Addr::LOCALHOST.into(), 8080),
+        Endian::Little,
+        b"\x7f\0\0\x01\x90\x1f"
+    );
+    compare!(
+        Ipv6Addr::LOCALHOST,
+        Endian::Little,
+        b"\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\x01"
+    );
+    compare!(
+        SocketAddrV6::new(Ipv6Addr::LOCALHOST, 443, 1, 2),
+        Endian::Big,
+        b"\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\x01\x01\xbb"
+    );
+}
+
 #[test]
 fn option() {
     compare!(Some(1_i32), Endian::Big, b"\0\0\0\x01");
@@ -86,6 +224,17 @@ fn phantom_data() {
     compare!(core::marker::PhantomData::<()>, b"");
 }
 
+#[test]
+fn smart_pointers() {
+    use std::{borrow::Cow, rc::Rc, sync::Arc};
+
+    compare!(Rc::new(3_u16), Endian::Big, b"\0\x03");
+    compare!(Arc::new(3_u16), Endian::Little, b"\x03\0");
+    compare!(Rc::<[u8]>::from(&[1, 2][..]), b"\x01\x02");
+    compare!(Cow::Borrowed(&[1_u8, 2][..]), b"\x01\x02");
+    compare!(Cow::<[u8]>::Owned(vec![1, 2]), b"\x01\x02");
+}
+
 #[test]
 fn tuple() {
     compare!((1_u8, 2_u8), b"\x01\x02");
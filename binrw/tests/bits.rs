@@ -0,0 +1,98 @@
+use binrw::{
+    bits::{BitOrder, BitReader, BitWriter},
+    io::Cursor,
+    Error,
+};
+
+#[test]
+fn read_msb0() {
+    let mut bits = BitReader::new(Cursor::new(b"\xa5\x0f\xff"), BitOrder::Msb0);
+    assert!(bits.read_bool().unwrap());
+    assert_eq!(bits.read_bits(3).unwrap(), 0b010);
+    assert!(!bits.is_aligned());
+    assert_eq!(bits.read_bits(8).unwrap(), 0x50);
+    assert_eq!(bits.read_bits(0).unwrap(), 0);
+    bits.align();
+    assert!(bits.is_aligned());
+    assert_eq!(bits.read_bits(4).unwrap(), 0xf);
+    assert!(bits.read_bits(8).unwrap_err().is_eof());
+}
+
+#[test]
+fn read_lsb0() {
+    let mut bits = BitReader::new(Cursor::new(b"\xa5\x0f"), BitOrder::Lsb0);
+    assert!(bits.read_bool().unwrap());
+    assert_eq!(bits.read_bits(3).unwrap(), 0b010);
+    assert_eq!(bits.read_bits(8).unwrap(), 0xfa);
+    assert_eq!(bits.read_bits(4).unwrap(), 0);
+    assert_eq!(bits.into_inner().position(), 2);
+}
+
+#[test]
+fn read_64_bits() {
+    let data = b"\x81\x02\x03\x04\x05\x06\x07\x08\x09";
+    let mut bits = BitReader::new(Cursor::new(data), BitOrder::Msb0);
+    assert!(bits.read_bool().unwrap());
+    assert_eq!(bits.read_bits(64).unwrap(), 0x0204_0608_0a0c_0e10);
+
+    let mut bits = BitReader::new(Cursor::new(data), BitOrder::Lsb0);
+    assert_eq!(bits.read_bits(64).unwrap(), 0x0807_0605_0403_0281);
+}
+
+#[test]
+fn write_round_trip() {
+    for order in [BitOrder::Msb0, BitOrder::Lsb0] {
+        let fields = [
+            (1, 1),
+            (0b010, 3),
+            (0x5a, 8),
+            (0, 2),
+            (u64::MAX, 64),
+            (3, 5),
+        ];
+
+        let mut bits = BitWriter::new(Cursor::new(Vec::new()), order);
+        for (value, count) in fields {
+            bits.write_bits(value, count).unwrap();
+        }
+        let data = bits.into_inner().unwrap().into_inner();
+        assert_eq!(data.len(), 11);
+
+        let mut bits = BitReader::new(Cursor::new(data), order);
+        for (value, count) in fields {
+            assert_eq!(bits.read_bits(count).unwrap(), value);
+        }
+    }
+}
+
+#[test]
+fn write_bytes() {
+    let mut out = Cursor::new(Vec::new());
+    let mut bits = BitWriter::new(&mut out, BitOrder::Msb0);
+    bits.write_bool(true).unwrap();
+    bits.write_bits(0b010, 3).unwrap();
+    bits.write_bits(0x50f, 12).unwrap();
+    assert!(bits.is_aligned());
+    bits.write_bits(0b11, 2).unwrap();
+    bits.finish().unwrap();
+    assert_eq!(out.into_inner(), b"\xa5\x0f\xc0");
+
+    let mut out = Cursor::new(Vec::new());
+    let mut bits = BitWriter::new(&mut out, BitOrder::Lsb0);
+    bits.write_bool(true).unwrap();
+    bits.write_bits(0b010, 3).unwrap();
+    bits.write_bits(0x0fa, 12).unwrap();
+    bits.write_bits(0b11, 2).unwrap();
+    bits.align().unwrap();
+    bits.align().unwrap();
+    assert_eq!(out.into_inner(), b"\xa5\x0f\x03");
+}
+
+#[test]
+fn write_out_of_range() {
+    let mut bits = BitWriter::new(Cursor::new(Vec::new()), BitOrder::Msb0);
+    assert!(matches!(
+        bits.write_bits(8, 3),
+        Err(Error::AssertFail { pos: 0, .. })
+    ));
+}
@@ -196,6 +196,40 @@ fn enum_magic_holey() {
     assert_eq!(result, Test::Right { a: 0x102 });
 }
 
+#[test]
+fn enum_magic_fallback() {
+    #[derive(BinRead, Debug, PartialEq)]
+    #[br(big)]
+    enum Test {
+        #[br(magic(0u8))]
+        Zero {
+            a: u16,
+        },
+        #[br(magic(1u8), assert(a != 0))]
+        One {
+            a: u8,
+        },
+        Unknown {
+            tag: u8,
+            a: u16,
+        },
+    }
+
+    assert_eq!(
+        Test::read(&mut Cursor::new(b"\x01\x02")).unwrap(),
+        Test::One { a: 2 }
+    );
+    assert_eq!(
+        Test::read(&mut Cursor::new(b"\x05\x01\x02")).unwrap(),
+        Test::Unknown { tag: 5, a: 0x102 }
+    );
+    // A known magic whose variant fails to parse falls back too
+    assert_eq!(
+        Test::read(&mut Cursor::new(b"\x01\0\x03")).unwrap(),
+        Test::Unknown { tag: 1, a: 3 }
+    );
+}
+
 #[test]
 fn enum_pre_assert() {
     #[derive(BinRead, Debug, PartialEq)]
@@ -229,6 +263,36 @@ fn enum_return_all_errors() {
 
     let error = Test::read(&mut Cursor::new("\0\x01")).expect_err("accepted bad data");
 
+    match error {
+        binrw::Error::EnumErrors {
+            pos,
+            variant_errors,
+        } => {
+            assert_eq!(pos, 0);
+            assert_eq!(variant_errors.len(), 1);
+            assert_eq!(variant_errors[0].0, "Two");
+            assert!(matches!(
+                variant_errors[0].1.root_cause(),
+                binrw::Error::Io(..)
+            ));
+        }
+        _ => panic!("wrong error type"),
+    }
+}
+
+#[test]
+fn enum_return_all_errors_mixed_magic() {
+    #[derive(BinRead, Debug)]
+    #[br(big, return_all_errors)]
+    enum Test {
+        #[br(magic(0u16))]
+        One { _a: u16 },
+        #[br(magic(1u8))]
+        Two { _a: u16 },
+    }
+
+    let error = Test::read(&mut Cursor::new("\x01\0")).expect_err("accepted bad data");
+
     match error {
         binrw::Error::EnumErrors {
             pos,
@@ -237,9 +301,15 @@ fn enum_return_all_errors() {
             assert_eq!(pos, 0);
             assert_eq!(variant_errors.len(), 2);
             assert_eq!(variant_errors[0].0, "One");
-            if let binrw::Error::BadMagic { pos, found } = &variant_errors[0].1 {
+            if let binrw::Error::BadMagic {
+                pos,
+                expected,
+                found,
+            } = &variant_errors[0].1
+            {
                 assert_eq!(pos, &0);
-                assert_eq!(&format!("{found:?}"), "1");
+                assert_eq!(&format!("{expected:?}"), "0");
+                assert_eq!(&format!("{found:?}"), "256");
             } else {
                 panic!("expected BadMagic; got {:?}", variant_errors[0].1);
             }
@@ -253,6 +323,100 @@ fn enum_return_all_errors() {
     }
 }
 
+#[test]
+fn enum_magic_dispatch() {
+    #[derive(BinRead, Debug, PartialEq)]
+    #[br(big)]
+    enum Test {
+        #[br(magic(b"AA"))]
+        A(u8),
+        #[br(magic(b"BB" | b"bb"))]
+        B(u16),
+        #[br(magic(b"CC"))]
+        C,
+    }
+
+    assert_eq!(Test::read(&mut Cursor::new(b"AA\x01")).unwrap(), Test::A(1));
+    assert_eq!(
+        Test::read(&mut Cursor::new(b"bb\0\x02")).unwrap(),
+        Test::B(2)
+    );
+    assert_eq!(Test::read(&mut Cursor::new(b"CC")).unwrap(), Test::C);
+
+    let mut data = Cursor::new(b"\0DD");
+    let expected = data.seek(SeekFrom::Start(1)).unwrap();
+    let error = Test::read(&mut data).expect_err("accepted bad data");
+    assert!(matches!(error, binrw::Error::NoVariantMatch { pos: 1 }));
+    assert_eq!(expected, data.stream_position().unwrap());
+}
+
+#[test]
+fn enum_magic_dispatch_duplicate() {
+    #[derive(BinRead, Debug, PartialEq)]
+    #[br(big)]
+    enum Test {
+        #[br(magic(0u8))]
+        Short(u8),
+        #[br(magic(0u8))]
+        Long(u16),
+    }
+
+    assert_eq!(
+        Test::read(&mut Cursor::new(b"\0\x01")).unwrap(),
+        Test::Short(1)
+    );
+    assert_eq!(
+        Test::read(&mut Cursor::new(b"\0\x01\x02")).unwrap(),
+        Test::Short(1)
+    );
+
+    #[derive(BinRead, Debug, PartialEq)]
+    #[br(big, assert(value > 1))]
+    enum Test2 {
+        #[br(magic(0u8))]
+        Short { value: u8 },
+        #[br(magic(0u8))]
+        Long { value: u16 },
+    }
+
+    assert_eq!(
+        Test2::read(&mut Cursor::new(b"\0\0\x02")).unwrap(),
+        Test2::Long { value: 2 }
+    );
+}
+
+#[test]
+#[deny(unreachable_patterns)]
+fn enum_magic_dispatch_duplicate_spelling() {
+    #[derive(BinRead, Debug, PartialEq)]
+    #[br(big)]
+    enum Test {
+        #[br(magic(0u8))]
+        Short(u8),
+        #[br(magic(0x00u8))]
+        Long(u16),
+    }
+
+    assert_eq!(
+        Test::read(&mut Cursor::new(b"\0\0\x01")).unwrap(),
+        Test::Short(0)
+    );
+
+    #[derive(BinRead, Debug, PartialEq)]
+    #[br(big, assert(value > 1))]
+    enum Test2 {
+        #[br(magic(b"A"))]
+        Short { value: u8 },
+        #[br(magic(b"\x41"))]
+        Long { value: u16 },
+    }
+
+    assert_eq!(
+        Test2::read(&mut Cursor::new(b"A\0\x02")).unwrap(),
+        Test2::Long { value: 2 }
+    );
+}
+
 #[test]
 fn enum_rewind_on_assert() {
     #[allow(dead_code)]
@@ -333,7 +497,7 @@ fn mixed_enum() {
         Test::Zero
     ));
     let error = Test::read(&mut Cursor::new(b"\x01")).expect_err("accepted bad data");
-    assert!(matches!(error, binrw::Error::EnumErrors { .. }));
+    assert!(matches!(error, binrw::Error::NoVariantMatch { .. }));
     let result = Test::read(&mut Cursor::new(b"\x02\0\x03\0\x04")).unwrap();
     assert_eq!(result, Test::Two { a: 3, b: 4 });
 }
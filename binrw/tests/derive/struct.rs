@@ -1,7 +1,7 @@
 use binrw::{
     args, binread,
     io::{Cursor, Seek, SeekFrom},
-    BinRead, BinResult, FilePtr, NullString,
+    BinRead, BinResult, Endian, FilePtr, NullString,
 };
 
 #[test]
@@ -207,6 +207,153 @@ fn deref_now() {
     );
 }
 
+#[test]
+fn offset_from_start() {
+    #[derive(BinRead, Debug, PartialEq)]
+    #[br(big, magic = b'C')]
+    struct Chunk {
+        #[br(offset_from_start)]
+        a: FilePtr<u8, u8>,
+        #[br(offset_from_start, offset = 1)]
+        b: FilePtr<u8, u8>,
+    }
+
+    #[derive(BinRead, Debug, PartialEq)]
+    #[br(big)]
+    struct Test {
+        padding: u8,
+        chunk: Chunk,
+    }
+
+    let result = Test::read(&mut Cursor::new(b"\xffC\x03\x03\x0a\x0b")).unwrap();
+    assert_eq!(*result.chunk.a, 0x0a);
+    assert_eq!(*result.chunk.b, 0x0b);
+}
+
+#[test]
+fn offset_from_start_enum() {
+    #[derive(BinRead, Debug, PartialEq)]
+    #[br(big, magic = b'E')]
+    enum Chunk {
+        #[br(magic = b'A')]
+        A {
+            #[br(offset_from_start)]
+            a: FilePtr<u8, u8>,
+        },
+        #[br(magic = b'B')]
+        B {
+            #[br(offset_from_start, offset = 1)]
+            b: FilePtr<u8, u8>,
+        },
+    }
+
+    #[derive(BinRead, Debug, PartialEq)]
+    #[br(big)]
+    struct Test {
+        padding: u8,
+        chunk: Chunk,
+    }
+
+    let result = Test::read(&mut Cursor::new(b"\xffEA\x03\x0a")).unwrap();
+    assert!(matches!(result.chunk, Chunk::A { a } if *a == 0x0a));
+    let result = Test::read(&mut Cursor::new(b"\xffEB\x02\x0b")).unwrap();
+    assert!(matches!(result.chunk, Chunk::B { b } if *b == 0x0b));
+
+    // Variants without magic are tried in turn instead of dispatched
+    #[derive(BinRead, Debug, PartialEq)]
+    #[br(big, magic = b'E')]
+    enum Untagged {
+        A {
+            #[br(assert(kind == 0))]
+            kind: u8,
+            #[br(offset_from_start)]
+            a: FilePtr<u8, u8>,
+        },
+        B {
+            kind: u8,
+            #[br(offset_from_start, offset = 1)]
+            b: FilePtr<u8, u8>,
+        },
+    }
+
+    let result = Untagged::read(&mut Cursor::new(b"E\x01\x02\x0b")).unwrap();
+    assert!(matches!(result, Untagged::B { b, .. } if *b == 0x0b));
+}
+
+#[test]
+fn offset_map() {
+    #[derive(BinRead, Debug, PartialEq)]
+    #[br(big)]
+    struct Test {
+        #[br(offset_map = |blocks| blocks * 4)]
+        a: FilePtr<u8, u8>,
+        #[br(offset = 1, offset_map(|blocks| blocks * 2))]
+        b: FilePtr<u8, u8>,
+    }
+
+    let result = Test::read(&mut Cursor::new(b"\x01\x02\xff\xff\x0a\x0b")).unwrap();
+    assert_eq!(*result.a, 0x0a);
+    assert_eq!(*result.b, 0x0b);
+}
+
+#[test]
+fn rest() {
+    #[binrw::binrw]
+    #[derive(Debug, PartialEq)]
+    #[brw(big)]
+    struct Test {
+        kind: u16,
+        #[br(rest)]
+        body: Vec<u8>,
+    }
+
+    let data = b"\0\x01\x02\x03\x04";
+    let result = Test::read(&mut Cursor::new(data)).unwrap();
+    assert_eq!(
+        result,
+        Test {
+            kind: 1,
+            body: vec![2, 3, 4]
+        }
+    );
+
+    let mut output = Cursor::new(vec![]);
+    binrw::BinWrite::write(&result, &mut output).unwrap();
+    assert_eq!(output.into_inner(), data);
+
+    let result = Test::read(&mut Cursor::new(b"\0\x01")).unwrap();
+    assert!(result.body.is_empty());
+}
+
+#[test]
+fn stride() {
+    #[binrw::binrw]
+    #[derive(Debug, PartialEq)]
+    #[brw(little)]
+    struct Test {
+        #[bw(calc = entries.len() as u8)]
+        len: u8,
+        #[br(count = len, stride = 4)]
+        #[bw(stride = 4)]
+        entries: Vec<u16>,
+        tail: u8,
+    }
+
+    let data = b"\x02\x01\0\xff\xff\x02\0\xff\xff\x03";
+    let result = Test::read(&mut Cursor::new(data)).unwrap();
+    assert_eq!(
+        result,
+        Test {
+            entries: vec![1, 2],
+            tail: 3
+        }
+    );
+
+    let mut output = Cursor::new(vec![]);
+    binrw::BinWrite::write(&result, &mut output).unwrap();
+    assert_eq!(output.into_inner(), b"\x02\x01\0\0\0\x02\0\0\0\x03");
+}
+
 #[test]
 fn move_args() {
     #[derive(Debug, PartialEq)]
@@ -424,6 +571,46 @@ fn if_alternate() {
     assert_eq!(result.a, 10);
 }
 
+#[test]
+fn if_not_eof() {
+    #[derive(BinRead, Debug, PartialEq)]
+    struct Test {
+        a: u8,
+        #[br(if_not_eof)]
+        b: Option<u16>,
+        #[br(if(a == 1, 5), if_not_eof)]
+        c: u8,
+    }
+
+    assert_eq!(
+        Test::read_le(&mut Cursor::new(b"\x01")).unwrap(),
+        Test {
+            a: 1,
+            b: None,
+            c: 5
+        }
+    );
+    assert_eq!(
+        Test::read_le(&mut Cursor::new(b"\x01\x02\0\x03")).unwrap(),
+        Test {
+            a: 1,
+            b: Some(2),
+            c: 3
+        }
+    );
+    assert_eq!(
+        Test::read_le(&mut Cursor::new(b"\x02\x02\0\x03")).unwrap(),
+        Test {
+            a: 2,
+            b: Some(2),
+            c: 5
+        }
+    );
+    assert!(Test::read_le(&mut Cursor::new(b"\x01\x02"))
+        .unwrap_err()
+        .is_eof());
+}
+
 #[test]
 fn ignore_and_default() {
     #[derive(Debug, Eq, PartialEq)]
@@ -455,6 +642,25 @@ fn ignore_and_default() {
     );
 }
 
+#[test]
+fn default_expr() {
+    #[binread]
+    #[derive(Debug, PartialEq)]
+    #[br(big, import(offset: u8))]
+    struct Test {
+        a: u8,
+        #[br(default = a + offset)]
+        b: u8,
+        #[br(temp, default(b * 2))]
+        c: u8,
+        #[br(default(c))]
+        d: u8,
+    }
+
+    let result = Test::read_args(&mut Cursor::new(b"\x02"), (3,)).unwrap();
+    assert_eq!(result, Test { a: 2, b: 5, d: 10 });
+}
+
 #[test]
 fn magic_byte() {
     #[derive(BinRead, Debug)]
@@ -481,6 +687,92 @@ fn magic_field() {
     assert_eq!(result, Test { b: b'B', d: b'D' });
 }
 
+#[test]
+fn magic_alternatives() {
+    #[derive(BinRead, Debug, PartialEq)]
+    #[br(magic = b"RIFF" | b"RIFX")]
+    struct Test {
+        #[br(magic(0u8 | 1u8))]
+        a: u8,
+    }
+
+    assert_eq!(
+        Test::read_le(&mut Cursor::new(b"RIFF\0\x02")).unwrap(),
+        Test { a: 2 }
+    );
+    assert_eq!(
+        Test::read_le(&mut Cursor::new(b"RIFX\x01\x03")).unwrap(),
+        Test { a: 3 }
+    );
+    let error = Test::read_le(&mut Cursor::new(b"RIFY\0\x02")).expect_err("accepted bad data");
+    assert!(matches!(error, binrw::Error::BadMagic { pos: 0, .. }));
+    let error = Test::read_le(&mut Cursor::new(b"RIFF\x02\x02")).expect_err("accepted bad data");
+    assert!(matches!(error, binrw::Error::BadMagic { pos: 4, .. }));
+}
+
+#[test]
+fn magic_alternatives_binding() {
+    #[derive(BinRead, Debug, PartialEq)]
+    #[br(magic = kind @ (b"RIFF" | b"RIFX"))]
+    struct Test {
+        #[br(calc = kind)]
+        kind: [u8; 4],
+        #[br(is_big = &kind == b"RIFX")]
+        size: u16,
+    }
+
+    assert_eq!(
+        Test::read_le(&mut Cursor::new(b"RIFF\x01\0")).unwrap(),
+        Test {
+            kind: *b"RIFF",
+            size: 1
+        }
+    );
+    assert_eq!(
+        Test::read_le(&mut Cursor::new(b"RIFX\0\x01")).unwrap(),
+        Test {
+            kind: *b"RIFX",
+            size: 1
+        }
+    );
+}
+
+#[test]
+fn set_endian() {
+    #[derive(BinRead, Debug, PartialEq)]
+    struct Inner(u16);
+
+    #[derive(BinRead, Debug, PartialEq)]
+    #[br(little)]
+    struct Test {
+        #[br(set_endian = if &bom == b"MM" { Endian::Big } else { Endian::Little })]
+        bom: [u8; 2],
+        a: u16,
+        inner: Inner,
+        #[br(little)]
+        b: u16,
+    }
+
+    assert_eq!(
+        Test::read(&mut Cursor::new(b"MM   ")).unwrap(),
+        Test {
+            bom: *b"MM",
+            a: 1,
+            inner: Inner(2),
+            b: 3
+        }
+    );
+    assert_eq!(
+        Test::read(&mut Cursor::new(b"II   ")).unwrap(),
+        Test {
+            bom: *b"II",
+            a: 1,
+            inner: Inner(2),
+            b: 3
+        }
+    );
+}
+
 #[test]
 fn magic_const() {
     use binrw::meta::ReadMagic;
@@ -533,6 +825,58 @@ fn map_stream_field() {
     );
 }
 
+#[test]
+fn size() {
+    #[derive(BinRead, Debug, PartialEq)]
+    #[br(big)]
+    struct Chunk {
+        len: u8,
+        #[br(size = len, parse_with = binrw::helpers::until_eof)]
+        data: Vec<u8>,
+        #[br(size = 3)]
+        short: u8,
+        #[br(size = 2, map_stream = |reader| reader, restore_position)]
+        peek: u16,
+        tail: u8,
+    }
+
+    assert_eq!(
+        Chunk::read(&mut Cursor::new(b"\x02\x01\x02\x03\xff\xff\x05\x06")).unwrap(),
+        Chunk {
+            len: 2,
+            data: vec![1, 2],
+            short: 3,
+            peek: 0x506,
+            tail: 5,
+        }
+    );
+
+    #[derive(BinRead, Debug)]
+    struct Bounded {
+        #[br(size = 1)]
+        _value: u16,
+    }
+
+    assert!(Bounded::read_le(&mut Cursor::new(b"\0\0"))
+        .unwrap_err()
+        .is_eof());
+
+    #[derive(BinRead, Debug)]
+    struct Negative {
+        _len: i8,
+        #[br(size = _len)]
+        _value: u8,
+    }
+
+    match Negative::read_le(&mut Cursor::new(b"\xff\0")).unwrap_err() {
+        binrw::Error::AssertFail { pos, message } => {
+            assert_eq!(pos, 1);
+            assert_eq!(message, "size -1 out of range");
+        }
+        error => panic!("bad error type: {error:?}"),
+    }
+}
+
 #[test]
 fn named_args_trailing_commas() {
     #[rustfmt::skip]
@@ -586,6 +930,59 @@ fn pad_size_to() {
     assert_eq!(result, Test { a: 1, b: 2 });
 }
 
+#[test]
+fn pad_size_to_struct() {
+    #[derive(BinRead, Debug, PartialEq)]
+    #[br(little, magic = b'R', pad_size_to = 4)]
+    struct Record {
+        a: u8,
+    }
+
+    #[derive(BinRead, Debug, PartialEq)]
+    #[br(little)]
+    struct Test {
+        records: [Record; 2],
+        b: u8,
+    }
+
+    let result = Test::read(&mut Cursor::new(b"R  R  ")).unwrap();
+    assert_eq!(
+        result,
+        Test {
+            records: [Record { a: 1 }, Record { a: 2 }],
+            b: 3
+        }
+    );
+}
+
+#[test]
+fn pad_size_to_struct_invalid() {
+    #[derive(BinRead, Debug)]
+    #[br(little, import(size: i32), pad_size_to = size)]
+    struct Test {
+        _a: u8,
+    }
+
+    let error = Test::read_args(&mut Cursor::new(b"\x01\x02"), (-1,)).unwrap_err();
+    assert!(matches!(error, binrw::Error::AssertFail { pos: 0, .. }));
+}
+
+#[test]
+fn pad_size_to_enum_variant() {
+    #[derive(BinRead, Debug, PartialEq)]
+    #[br(little)]
+    enum Record {
+        #[br(magic = 0u8, pad_size_to = 4)]
+        A(u8),
+        #[br(magic = 1u8)]
+        B(u16),
+    }
+
+    let mut data = Cursor::new(b"    ");
+    assert_eq!(Record::read(&mut data).unwrap(), Record::A(1));
+    assert_eq!(Record::read(&mut data).unwrap(), Record::B(2));
+}
+
 #[test]
 fn parse_with_default_args() {
     #[derive(Clone)]
@@ -843,6 +1240,35 @@ fn top_level_assert_self_weird() {
     } if message == "verify failed"));
 }
 
+#[test]
+fn top_level_parse_with() {
+    #[binrw::parser(reader, endian)]
+    fn parse_swapped(offset: u8) -> BinResult<Test> {
+        let b = u8::read_options(reader, endian, ())?;
+        let a = u8::read_options(reader, endian, ())?;
+        Ok(Test { a: a + offset, b })
+    }
+
+    #[derive(BinRead, Debug, PartialEq)]
+    #[br(big, magic = b"T", import(offset: u8), parse_with = parse_swapped)]
+    #[br(assert(a != b, "a == b"))]
+    struct Test {
+        a: u8,
+        b: u8,
+    }
+
+    let mut data = Cursor::new(b"T\x01\x02");
+    assert_eq!(
+        Test::read_args(&mut data, (1,)).unwrap(),
+        Test { a: 3, b: 1 }
+    );
+
+    let mut data = Cursor::new(b"T\x01\x00");
+    let err = Test::read_args(&mut data, (1,)).expect_err("accepted bad data");
+    assert!(matches!(err, binrw::Error::AssertFail { pos: 0, .. }));
+    assert_eq!(data.stream_position().unwrap(), 0);
+}
+
 #[test]
 fn rewind_on_assert() {
     #[allow(dead_code)]
@@ -972,3 +1398,38 @@ fn no_clone_needed_for_parse_with() {
         }
     );
 }
+
+#[cfg(feature = "std")]
+#[test]
+fn warn() {
+    #[derive(BinRead, Debug, PartialEq)]
+    #[br(little)]
+    struct Test {
+        a: u8,
+        #[br(warn(b == 0), warn(b < a, "b ({}) >= a ({})", b, a))]
+        b: u8,
+    }
+
+    let mut data = Cursor::new(b"\0\x01\x02\x03");
+    data.set_position(2);
+    let (result, warnings) = binrw::diagnostics::collect(|| Test::read(&mut data));
+    assert_eq!(result.unwrap(), Test { a: 2, b: 3 });
+    assert_eq!(
+        warnings,
+        [
+            binrw::diagnostics::Diagnostic {
+                pos: 2,
+                message: "assertion failed: `b == 0`".into(),
+            },
+            binrw::diagnostics::Diagnostic {
+                pos: 2,
+                message: "b (3) >= a (2)".into(),
+            },
+        ]
+    );
+
+    let (result, warnings) =
+        binrw::diagnostics::collect(|| Test::read(&mut Cursor::new(b"\x02\0")));
+    assert_eq!(result.unwrap(), Test { a: 2, b: 0 });
+    assert!(warnings.is_empty());
+}
@@ -10,3 +10,20 @@ fn derive_generic() {
     let result = Test::<u8>::read_le(&mut Cursor::new(b"\0\x01\x02")).unwrap();
     assert_eq!(result.a, [0, 1, 2]);
 }
+
+#[test]
+fn derive_phantom_data() {
+    use core::marker::PhantomData;
+
+    struct NotBinRead;
+
+    #[derive(BinRead)]
+    #[br(little)]
+    struct Test<T> {
+        a: u16,
+        _marker: PhantomData<T>,
+    }
+
+    let result = Test::<NotBinRead>::read(&mut Cursor::new(b"\x01\0")).unwrap();
+    assert_eq!(result.a, 1);
+}
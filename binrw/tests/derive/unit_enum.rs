@@ -82,6 +82,25 @@ fn unit_enum_magic_bytes() {
     assert_eq!(Test::read(&mut Cursor::new(b"two0")).unwrap(), Test::Two);
 }
 
+#[test]
+fn unit_enum_magic_alternatives() {
+    #[derive(BinRead, Debug, Eq, PartialEq)]
+    #[br(little)]
+    enum Test {
+        #[br(magic = b'0' | b'1')]
+        Small,
+
+        #[br(magic(b'8' | b'9'))]
+        Big,
+    }
+
+    assert_eq!(Test::read(&mut Cursor::new(b"0")).unwrap(), Test::Small);
+    assert_eq!(Test::read(&mut Cursor::new(b"1")).unwrap(), Test::Small);
+    assert_eq!(Test::read(&mut Cursor::new(b"9")).unwrap(), Test::Big);
+    let error = Test::read(&mut Cursor::new(b"5")).expect_err("accepted bad data");
+    assert!(matches!(error, binrw::Error::NoVariantMatch { .. }));
+}
+
 #[test]
 fn unit_enum_magic_pre_assert() {
     #[derive(BinRead, Debug, Eq, PartialEq)]
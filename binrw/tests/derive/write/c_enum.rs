@@ -72,3 +72,25 @@ fn magic_enum_round_trip() {
 
     assert_eq!(x.into_inner(), data);
 }
+
+#[test]
+fn magic_enum_and_variant_round_trip() {
+    #[derive(BinRead, BinWrite)]
+    #[brw(magic = b"#")]
+    enum Test {
+        #[brw(magic = b"abc")]
+        A,
+
+        #[brw(magic = b"123")]
+        B,
+    }
+
+    let data = b"#123#abc";
+    let test: [Test; 2] = Cursor::new(data).read_be().unwrap();
+
+    let mut x = Cursor::new(Vec::new());
+
+    test.write_options(&mut x, Endian::Big, ()).unwrap();
+
+    assert_eq!(x.into_inner(), data);
+}
@@ -0,0 +1,74 @@
+use binrw::{binrw, binwrite, io::Cursor, BinRead, BinWrite, BinWriterExt};
+
+#[test]
+fn count_matches() {
+    #[binrw]
+    #[brw(big)]
+    #[derive(Debug, PartialEq)]
+    struct Test {
+        len: u16,
+        #[brw(count = len)]
+        data: Vec<u8>,
+    }
+
+    let value = Test::read(&mut Cursor::new(b"\0\x02\x01\x02")).unwrap();
+    assert_eq!(
+        value,
+        Test {
+            len: 2,
+            data: vec![1, 2]
+        }
+    );
+
+    let mut x = Cursor::new(Vec::new());
+    value.write(&mut x).unwrap();
+    assert_eq!(x.into_inner(), b"\0\x02\x01\x02");
+}
+
+#[test]
+fn count_mismatch() {
+    #[derive(BinWrite)]
+    struct Test {
+        len: u16,
+        #[bw(count = len * 2)]
+        data: Vec<u8>,
+    }
+
+    let mut x = Cursor::new(Vec::new());
+    Test {
+        len: 2,
+        data: vec![1, 2, 3, 4],
+    }
+    .write_le(&mut x)
+    .unwrap();
+
+    let mut x = Cursor::new(Vec::new());
+    let err = x
+        .write_be(&Test {
+            len: 1,
+            data: vec![1, 2, 3],
+        })
+        .unwrap_err();
+    match err {
+        binrw::Error::AssertFail { pos, message } => {
+            assert_eq!(pos, 2);
+            assert_eq!(message, "count 2 does not match length 3");
+        }
+        _ => panic!("Assert error expected"),
+    }
+}
+
+#[test]
+fn count_with_map() {
+    #[binwrite]
+    struct Test {
+        #[bw(try_calc = u8::try_from(name.len()))]
+        len: u8,
+        #[bw(count = len, map = |s: &String| s.as_bytes().to_vec())]
+        name: String,
+    }
+
+    let mut x = Cursor::new(Vec::new());
+    Test { name: "abc".into() }.write_le(&mut x).unwrap();
+    assert_eq!(x.into_inner(), b"\x03abc");
+}
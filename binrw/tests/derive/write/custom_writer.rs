@@ -1,4 +1,4 @@
-use binrw::{io::Cursor, BinWrite, Endian};
+use binrw::{binrw, io::Cursor, BinRead, BinWrite, Endian};
 
 #[test]
 fn custom_writer() {
@@ -24,3 +24,23 @@ fn custom_writer() {
 
     assert_eq!(x.into_inner(), b"\x01abcd");
 }
+
+#[test]
+fn closures() {
+    #[binrw]
+    #[derive(Debug, PartialEq)]
+    #[brw(little)]
+    struct Test {
+        bias: u8,
+        #[br(parse_with = |reader, endian, _: ()| u8::read_options(reader, endian, ()).map(|v| v + bias))]
+        #[bw(write_with = |value: &u8, writer, endian, _: ()| (value - bias).write_options(writer, endian, ()))]
+        value: u8,
+    }
+
+    let value = Test::read(&mut Cursor::new(b"\x01\x02")).unwrap();
+    assert_eq!(value, Test { bias: 1, value: 3 });
+
+    let mut x = Cursor::new(Vec::new());
+    value.write(&mut x).unwrap();
+    assert_eq!(x.into_inner(), b"\x01\x02");
+}
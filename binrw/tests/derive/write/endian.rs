@@ -1,4 +1,4 @@
-use binrw::{io::Cursor, BinWrite};
+use binrw::{io::Cursor, BinRead, BinWrite, Endian};
 
 #[derive(BinWrite)]
 struct TestEndian {
@@ -65,3 +65,40 @@ fn top_level_endian() {
 
     assert_eq!(x.into_inner(), [1, 0, 2, 0, 0, 0, 0, 3, 0, 0, 0, 4]);
 }
+
+#[test]
+fn set_endian_round_trip() {
+    #[derive(BinRead, BinWrite, Debug, PartialEq)]
+    #[brw(little)]
+    struct Test {
+        #[br(set_endian = if bom == 0xfeff { Endian::Little } else { Endian::Big })]
+        #[bw(set_endian = if *bom == 0xfeff { Endian::Little } else { Endian::Big })]
+        bom: u16,
+        x: u16,
+        y: u32,
+    }
+
+    for (value, data) in [
+        (
+            Test {
+                bom: 0xfeff,
+                x: 1,
+                y: 2,
+            },
+            [0xff, 0xfe, 1, 0, 2, 0, 0, 0],
+        ),
+        (
+            Test {
+                bom: 0xfffe,
+                x: 1,
+                y: 2,
+            },
+            [0xfe, 0xff, 0, 1, 0, 0, 0, 2],
+        ),
+    ] {
+        let mut x = Cursor::new(Vec::new());
+        value.write(&mut x).unwrap();
+        assert_eq!(x.get_ref(), &data);
+        assert_eq!(Test::read(&mut Cursor::new(&data)).unwrap(), value);
+    }
+}
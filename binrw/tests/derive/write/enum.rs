@@ -71,3 +71,29 @@ fn enum_one_way() {
         b"BBB\xBB\xAA\0\0\0\x02CCCAAA\x03\x02\x01\0\xFF"
     );
 }
+
+#[test]
+fn enum_and_variant_magic_round_trip() {
+    #[derive(BinRead, BinWrite, Debug, PartialEq)]
+    #[brw(big, magic = 0x7F_u8)]
+    enum Test {
+        #[brw(magic = b"AA")]
+        A(u16),
+
+        #[brw(little, magic = 0x4242_u16)]
+        B(u16),
+
+        #[brw(magic = b"CC")]
+        C,
+    }
+
+    let data = b"\x7FAA\x01\x02\x7F\x42\x42\x01\x02\x7FCC";
+    let test: [Test; 3] = Cursor::new(data).read_be().unwrap();
+    assert_eq!(test, [Test::A(0x102), Test::B(0x201), Test::C]);
+
+    let mut x = Cursor::new(Vec::new());
+
+    test.write_options(&mut x, Endian::Big, ()).unwrap();
+
+    assert_eq!(x.into_inner(), data);
+}
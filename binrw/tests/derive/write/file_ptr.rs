@@ -0,0 +1,127 @@
+use binrw::{binrw, io::Cursor, BinRead, BinWrite, FilePtr16, FilePtr32, FilePtr8};
+
+#[test]
+fn file_ptr_offset() {
+    #[binrw]
+    #[derive(Debug, PartialEq)]
+    struct Test {
+        a: u8,
+        #[brw(offset = 2)]
+        b: FilePtr8<u8>,
+    }
+
+    let value = Test::read_le(&mut Cursor::new(b"\x07\x01\xaa\xcc")).unwrap();
+    assert_eq!(*value.b, 0xcc);
+
+    let mut out = Cursor::new(Vec::new());
+    value.write_le(&mut out).unwrap();
+    assert_eq!(out.into_inner(), b"\x07\x01\0\xcc");
+}
+
+#[test]
+fn file_ptr_offset_from_start() {
+    #[binrw]
+    #[derive(Debug, PartialEq)]
+    #[brw(magic = b"M")]
+    struct Test {
+        #[brw(offset_from_start)]
+        a: FilePtr8<u8>,
+    }
+
+    let mut out = Cursor::new(vec![0xff]);
+    out.set_position(1);
+    Test {
+        a: FilePtr8 { ptr: 3, value: 5 },
+    }
+    .write_le(&mut out)
+    .unwrap();
+    assert_eq!(out.get_ref(), b"\xffM\x03\0\x05");
+
+    out.set_position(1);
+    assert_eq!(*Test::read_le(&mut out).unwrap().a, 5);
+}
+
+#[test]
+fn file_ptr_deferred() {
+    #[binrw]
+    #[derive(Debug, PartialEq)]
+    #[brw(big)]
+    struct Test {
+        #[bw(deferred)]
+        a: FilePtr32<u16>,
+        #[bw(deferred)]
+        b: FilePtr16<u8>,
+        c: u8,
+    }
+
+    let mut out = Cursor::new(Vec::new());
+    Test {
+        a: FilePtr32 { ptr: 0, value: 0x102 },
+        b: FilePtr16 { ptr: 0, value: 3 },
+        c: 4,
+    }
+    .write(&mut out)
+    .unwrap();
+    assert_eq!(out.get_ref(), b"\0\0\0\x07\0\x09\x04\x01\x02\x03");
+
+    out.set_position(0);
+    let value = Test::read(&mut out).unwrap();
+    assert_eq!((*value.a, *value.b, value.c), (0x102, 3, 4));
+
+    let mut round_trip = Cursor::new(Vec::new());
+    value.write(&mut round_trip).unwrap();
+    assert_eq!(round_trip.into_inner(), out.into_inner());
+}
+
+#[test]
+fn file_ptr_deferred_offset() {
+    #[binrw]
+    #[derive(Debug, PartialEq)]
+    #[br(import(offset: u64))]
+    #[bw(import(offset: u64))]
+    struct Test {
+        #[brw(offset = offset)]
+        #[bw(deferred)]
+        a: FilePtr8<u8>,
+        #[bw(pad_after = 2)]
+        b: u8,
+    }
+
+    let mut out = Cursor::new(Vec::new());
+    Test {
+        a: FilePtr8 { ptr: 0, value: 5 },
+        b: 6,
+    }
+    .write_le_args(&mut out, (1,))
+    .unwrap();
+    assert_eq!(out.get_ref(), b"\x03\x06\0\0\x05");
+
+    out.set_position(0);
+    assert_eq!(*Test::read_le_args(&mut out, (1,)).unwrap().a, 5);
+
+    let error = Test {
+        a: FilePtr8 { ptr: 0, value: 5 },
+        b: 6,
+    }
+    .write_le_args(&mut Cursor::new(Vec::new()), (5,))
+    .unwrap_err();
+    assert!(matches!(error, binrw::Error::AssertFail { pos: 0, .. }));
+}
+
+#[test]
+fn file_ptr_deferred_out_of_range() {
+    #[derive(BinWrite)]
+    struct Test {
+        #[bw(deferred)]
+        a: FilePtr8<u8>,
+    }
+
+    let mut out = Cursor::new(Vec::new());
+    out.set_position(0x100);
+    let error = Test {
+        a: FilePtr8 { ptr: 0, value: 5 },
+    }
+    .write_le(&mut out)
+    .unwrap_err();
+    assert!(matches!(error, binrw::Error::AssertFail { pos: 0x100, .. }));
+}
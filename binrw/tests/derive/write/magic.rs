@@ -39,3 +39,58 @@ fn magic_one_way() {
 
     assert_eq!(out.into_inner(), data);
 }
+
+#[test]
+fn magic_alternatives_round_trip() {
+    #[derive(BinRead, BinWrite, Debug, PartialEq)]
+    #[brw(little, magic = kind @ (b"RIFF" | b"RIFX"))]
+    struct Test {
+        #[br(calc = kind)]
+        #[bw(ignore)]
+        kind: [u8; 4],
+        x: u16,
+    }
+
+    for data in [b"RIFF\x01\0", b"RIFX\x02\0"] {
+        let test = Test::read(&mut Cursor::new(data)).unwrap();
+        assert_eq!(&test.kind, &data[..4]);
+
+        let mut out = Cursor::new(Vec::new());
+        test.write(&mut out).unwrap();
+        assert_eq!(out.into_inner(), data);
+    }
+}
+
+#[test]
+fn magic_alternatives_write_first() {
+    #[derive(BinWrite)]
+    #[bw(little, magic = b"RIFF" | b"RIFX")]
+    struct Test {
+        x: u16,
+    }
+
+    let mut out = Cursor::new(Vec::new());
+    Test { x: 1 }.write(&mut out).unwrap();
+    assert_eq!(out.into_inner(), b"RIFF\x01\0");
+}
+
+#[test]
+fn magic_alternatives_write_invalid() {
+    #[derive(BinWrite)]
+    #[bw(little, magic = kind @ (b"RIFF" | b"RIFX"))]
+    struct Test {
+        #[bw(ignore)]
+        kind: [u8; 4],
+        x: u16,
+    }
+
+    let mut out = Cursor::new(Vec::new());
+    let error = Test {
+        kind: *b"RIFY",
+        x: 1,
+    }
+    .write(&mut out)
+    .expect_err("wrote invalid magic");
+    assert!(matches!(error, binrw::Error::AssertFail { pos: 0, .. }));
+    assert!(out.into_inner().is_empty());
+}
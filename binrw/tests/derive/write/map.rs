@@ -1,4 +1,4 @@
-use binrw::{binwrite, io::Cursor, BinWrite};
+use binrw::{binrw, binwrite, io::Cursor, BinRead, BinWrite};
 
 #[test]
 fn map_field() {
@@ -121,3 +121,31 @@ fn try_map() {
         value: u8,
     }
 }
+
+#[test]
+fn map_repr_round_trip() {
+    #[binrw]
+    #[derive(Debug, PartialEq)]
+    #[brw(big, repr = u16)]
+    struct Test(u8, u8);
+
+    impl From<u16> for Test {
+        fn from(value: u16) -> Self {
+            let [a, b] = value.to_be_bytes();
+            Self(a, b)
+        }
+    }
+
+    impl From<&Test> for u16 {
+        fn from(value: &Test) -> Self {
+            u16::from_be_bytes([value.0, value.1])
+        }
+    }
+
+    let value = Test::read(&mut Cursor::new(b"\x01\x02")).unwrap();
+    assert_eq!(value, Test(1, 2));
+
+    let mut x = Cursor::new(Vec::new());
+    value.write(&mut x).unwrap();
+    assert_eq!(x.into_inner(), b"\x01\x02");
+}
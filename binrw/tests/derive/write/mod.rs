@@ -3,8 +3,10 @@ mod assert;
 mod binread_temp;
 mod c_enum;
 mod calc;
+mod count;
 mod custom_writer;
 mod endian;
+mod file_ptr;
 mod r#enum;
 mod if_cond;
 mod ignore;
@@ -14,6 +16,7 @@ mod map;
 mod map_stream;
 mod padding;
 mod restore_position;
+mod seek;
 mod simple;
 mod stream;
 mod struct_generic;
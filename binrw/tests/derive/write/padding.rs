@@ -73,3 +73,67 @@ fn padding_one_way() {
 
     assert_eq!(x.into_inner(), data);
 }
+
+#[test]
+fn pad_size_to_struct_round_trip() {
+    #[derive(BinRead, BinWrite, Debug, PartialEq)]
+    #[brw(little, magic = b'R', pad_size_to = 4)]
+    struct Record {
+        a: u8,
+    }
+
+    #[derive(BinRead, BinWrite, Debug, PartialEq)]
+    #[brw(little)]
+    enum Test {
+        #[brw(magic = 0u8, pad_size_to = 8)]
+        A(Record, u8),
+        #[brw(magic = 1u8)]
+        B(u8),
+    }
+
+    let data = [0, b'R', 1, 0, 0, 2, 0, 0, 1, 3];
+    let mut x = Cursor::new(Vec::new());
+    Test::A(Record { a: 1 }, 2).write(&mut x).unwrap();
+    Test::B(3).write(&mut x).unwrap();
+    assert_eq!(x.get_ref(), &data);
+
+    let mut x = Cursor::new(&data);
+    assert_eq!(Test::read(&mut x).unwrap(), Test::A(Record { a: 1 }, 2));
+    assert_eq!(Test::read(&mut x).unwrap(), Test::B(3));
+}
+
+#[test]
+fn fill() {
+    #[derive(BinWrite)]
+    #[bw(fill = 0xff, pad_size_to = 8)]
+    struct Test {
+        #[bw(pad_before = 1)]
+        a: u8,
+        #[bw(align_before = 4, fill = b' ')]
+        b: u8,
+        #[bw(pad_after = 1)]
+        c: u8,
+    }
+
+    let mut x = Cursor::new(Vec::new());
+    Test { a: 1, b: 2, c: 3 }.write_le(&mut x).unwrap();
+    assert_eq!(x.into_inner(), b"\xff\x01  \x02\x03\xff\xff");
+}
+
+#[test]
+fn fill_round_trip() {
+    #[binrw::binrw]
+    #[derive(Debug, PartialEq)]
+    struct Test {
+        #[brw(pad_after = 2)]
+        #[bw(fill = 0xff)]
+        a: u8,
+        b: u8,
+    }
+
+    let mut x = Cursor::new(Vec::new());
+    Test { a: 1, b: 2 }.write_le(&mut x).unwrap();
+    assert_eq!(x.get_ref(), b"\x01\xff\xff\x02");
+    x.set_position(0);
+    assert_eq!(Test::read_le(&mut x).unwrap(), Test { a: 1, b: 2 });
+}
@@ -0,0 +1,17 @@
+use binrw::{io::Cursor, BinWrite};
+
+#[test]
+fn seek_before_writing() {
+    #[derive(BinWrite)]
+    struct Test {
+        x: u8,
+        #[bw(seek_before = binrw::io::SeekFrom::Start(4))]
+        y: u8,
+        #[bw(seek_before = binrw::io::SeekFrom::Current(-3))]
+        z: u8,
+    }
+
+    let mut x = Cursor::new(Vec::new());
+    Test { x: 1, y: 2, z: 3 }.write_le(&mut x).unwrap();
+    assert_eq!(x.into_inner(), b"\x01\0\x03\0\x02");
+}
@@ -1,4 +1,4 @@
-use binrw::{io::Cursor, BinRead, BinWrite, BinWriterExt, Endian};
+use binrw::{binrw, io::Cursor, BinRead, BinWrite, BinWriterExt, Endian};
 
 #[derive(BinWrite)]
 struct Test {
@@ -83,3 +83,40 @@ fn round_trip_2() {
 
     assert_eq!(x.into_inner() == bytes, y.into_inner() == bytes_conj);
 }
+
+#[test]
+fn tuple_struct_field_attrs() {
+    #[binrw]
+    #[derive(Debug, PartialEq)]
+    #[brw(big, magic = b"TS")]
+    struct Test(
+        #[br(temp)]
+        #[bw(calc = self_2.len() as u8)]
+        u8,
+        #[brw(little)] u16,
+        #[br(count = self_0)] Vec<u8>,
+        #[brw(pad_before = 1, align_after = 4)] u8,
+        #[br(map = |x: u8| x != 0)]
+        #[bw(map = |x| u8::from(*x))]
+        bool,
+        #[br(if(self_4))]
+        #[bw(if(self_4 != 0))]
+        Option<u16>,
+        #[brw(restore_position)] u8,
+        #[br(assert(self_7 == self_6))] u8,
+        #[br(ignore)]
+        #[bw(ignore)]
+        u32,
+    );
+
+    let data = b"TS\x02\x01\0\xaa\xbb\0\x05\0\0\0\x01\x12\x34\x56";
+    let test = Test::read(&mut Cursor::new(data)).unwrap();
+    assert_eq!(
+        test,
+        Test(1, vec![0xaa, 0xbb], 5, true, Some(0x1234), 0x56, 0x56, 0)
+    );
+
+    let mut out = Cursor::new(Vec::new());
+    test.write(&mut out).unwrap();
+    assert_eq!(out.into_inner(), data);
+}
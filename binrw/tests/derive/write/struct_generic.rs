@@ -17,3 +17,26 @@ fn derive_allows_default() {
         .unwrap();
     assert_eq!(b"\0", &result[..]);
 }
+
+#[test]
+fn derive_phantom_data() {
+    use core::marker::PhantomData;
+
+    struct NotBinWrite;
+
+    #[derive(BinWrite)]
+    #[bw(little)]
+    struct Test<T> {
+        a: u16,
+        _marker: PhantomData<fn() -> T>,
+    }
+
+    let mut result = Vec::new();
+    Test::<NotBinWrite> {
+        a: 1,
+        _marker: PhantomData,
+    }
+    .write(&mut Cursor::new(&mut result))
+    .unwrap();
+    assert_eq!(b"\x01\0", &result[..]);
+}
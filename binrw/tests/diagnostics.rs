@@ -0,0 +1,175 @@
+#![cfg(feature = "std")]
+
+use binrw::{
+    diagnostics::{self, Diagnostic},
+    io::Cursor,
+    BinRead, BinReaderExt, BinResult,
+};
+
+#[test]
+fn collect() {
+    diagnostics::warn(0, "ignored");
+    assert!(!diagnostics::is_collecting());
+
+    let ((), outer) = diagnostics::collect(|| {
+        diagnostics::warn(1, "outer");
+        let ((), inner) = diagnostics::collect(|| {
+            assert!(diagnostics::is_collecting());
+            diagnostics::warn(2, "inner");
+        });
+        assert_eq!(
+            inner,
+            [Diagnostic {
+                pos: 2,
+                message: "inner".into()
+            }]
+        );
+        diagnostics::warn(3, "outer again");
+    });
+
+    assert!(!diagnostics::is_collecting());
+    assert_eq!(
+        outer,
+        [
+            Diagnostic {
+                pos: 1,
+                message: "outer".into()
+            },
+            Diagnostic {
+                pos: 3,
+                message: "outer again".into()
+            },
+        ]
+    );
+}
+
+#[test]
+fn collect_panic() {
+    let result = std::panic::catch_unwind(|| {
+        diagnostics::collect(|| {
+            diagnostics::warn(0, "lost");
+            panic!("oops");
+        })
+    });
+    assert!(result.is_err());
+    assert!(!diagnostics::is_collecting());
+}
+
+#[test]
+fn custom_parser() {
+    #[binrw::parser(reader)]
+    fn checked_u8() -> BinResult<u8> {
+        let pos = reader.stream_position()?;
+        let value = <u8>::read_le(reader)?;
+        if value > 9 {
+            diagnostics::warn(pos, format!("{value} is out of range"));
+        }
+        Ok(value)
+    }
+
+    #[derive(BinRead)]
+    struct Test {
+        #[br(parse_with = checked_u8)]
+        a: u8,
+        #[br(parse_with = checked_u8)]
+        b: u8,
+    }
+
+    let (result, warnings) = diagnostics::collect(|| Cursor::new(b"\x01\x0a").read_le::<Test>());
+    let result = result.unwrap();
+    assert_eq!((result.a, result.b), (1, 10));
+    assert_eq!(
+        warnings,
+        [Diagnostic {
+            pos: 1,
+            message: "10 is out of range".into()
+        }]
+    );
+}
+
+#[test]
+fn display() {
+    let diagnostic = Diagnostic {
+        pos: 0x10,
+        message: "checksum mismatch".into(),
+    };
+    assert_eq!(diagnostic.to_string(), "checksum mismatch at 0x10");
+}
+
+#[test]
+fn try_field() {
+    #[derive(BinRead)]
+    #[br(big)]
+    struct Test {
+        a: u8,
+        #[br(try)]
+        b: u16,
+    }
+
+    let (result, warnings) = diagnostics::collect(|| Cursor::new(b"\x01\x02").read_le::<Test>());
+    let result = result.unwrap();
+    assert_eq!((result.a, result.b), (1, 0));
+    assert!(warnings.is_empty());
+}
+
+#[test]
+fn recover() {
+    #[derive(BinRead, Debug, PartialEq)]
+    #[br(big, recover)]
+    struct Test {
+        a: u8,
+        #[br(assert(b < 10, "b is {}", b))]
+        b: u8,
+        c: u16,
+        #[br(calc = a + 1)]
+        d: u8,
+    }
+
+    let (result, warnings) =
+        diagnostics::collect(|| Cursor::new(b"\x01\x02\x03").read_le::<Test>());
+    assert_eq!(
+        result.unwrap(),
+        Test {
+            a: 1,
+            b: 2,
+            c: 0,
+            d: 2
+        }
+    );
+    assert_eq!(warnings.len(), 1);
+    assert_eq!(warnings[0].pos, 2);
+    assert!(warnings[0]
+        .message
+        .starts_with("While parsing field 'c' in Test: "));
+
+    let (result, warnings) = diagnostics::collect(|| Cursor::new(b"\x01\x0a").read_le::<Test>());
+    assert!(matches!(
+        result.unwrap_err(),
+        binrw::Error::AssertFail { .. }
+    ));
+    assert!(warnings.is_empty());
+
+    #[derive(BinRead, Debug, PartialEq)]
+    #[br(big)]
+    enum Outer {
+        #[br(magic = 0u8, recover)]
+        A { a: u16, b: u8 },
+    }
+
+    let (result, warnings) = diagnostics::collect(|| Cursor::new(b"\0\x01").read_le::<Outer>());
+    assert_eq!(result.unwrap(), Outer::A { a: 0, b: 0 });
+    assert_eq!(warnings.len(), 1);
+    assert!(warnings[0]
+        .message
+        .starts_with("While parsing field 'a' in Outer::A: "));
+
+    assert_eq!(
+        Test::read(&mut Cursor::new(b"\x01")).unwrap(),
+        Test {
+            a: 1,
+            b: 0,
+            c: 0,
+            d: 2
+        }
+    );
+}
@@ -20,3 +20,51 @@ fn endian_to_string() {
     assert_eq!(Endian::Big.to_string(), "Big");
     assert_eq!(Endian::Little.to_string(), "Little");
 }
+
+#[test]
+fn fixed_endian_types() {
+    use binrw::{
+        endian::{f32be, i16le, u16be, u16le, u64be},
+        io::Cursor,
+        BinRead, BinWrite,
+    };
+
+    #[derive(BinRead, BinWrite, Debug, PartialEq)]
+    struct Test {
+        a: u16le,
+        b: u16be,
+        c: i16le,
+        d: f32be,
+        e: u16,
+    }
+
+    let data = b"\x01\x02\x01\x02\xfe\xff\x3f\x80\0\0\x01\x02";
+    let expected = Test {
+        a: u16le(0x201),
+        b: u16be(0x102),
+        c: i16le(-2),
+        d: f32be(1.0),
+        e: 0x201,
+    };
+
+    let test = Test::read_le(&mut Cursor::new(data)).unwrap();
+    assert_eq!(test, expected);
+
+    let mut out = Cursor::new(Vec::new());
+    test.write_be(&mut out).unwrap();
+    assert_eq!(
+        out.into_inner(),
+        b"\x01\x02\x01\x02\xfe\xff\x3f\x80\0\0\x02\x01"
+    );
+
+    assert_eq!(
+        u64be::read(&mut Cursor::new(b"\0\0\0\0\0\0\0\x05")).unwrap(),
+        u64be(5)
+    );
+    let mut out = Cursor::new(Vec::new());
+    i16le(-2).write(&mut out).unwrap();
+    assert_eq!(out.into_inner(), b"\xfe\xff");
+
+    assert_eq!(u64be::from(5), u64be(5));
+    assert_eq!(u64::from(u64be(5)), 5);
+}
@@ -76,10 +76,12 @@ fn display() {
         "{}",
         Error::BadMagic {
             pos: 0x42,
+            expected: Box::new(48879),
             found: Box::new(57005)
         }
     );
     assert!(err.contains("0x42"));
+    assert!(err.contains("48879"));
     assert!(err.contains("57005"));
 
     let err = format!(
@@ -241,7 +243,12 @@ fn no_seek_data_enum() {
                 (BacktraceFrame::Message(m), BacktraceFrame::Custom(e)) => {
                     assert_eq!(m, "rewinding after a failure");
                     match e.downcast_ref::<binrw::Error>() {
-                        Some(binrw::Error::AssertFail { pos, .. }) => assert_eq!(*pos, 0),
+                        Some(binrw::Error::EnumErrors { variant_errors, .. }) => {
+                            assert!(matches!(
+                                variant_errors[..],
+                                [("A", binrw::Error::AssertFail { pos: 0, .. })]
+                            ));
+                        }
                         e => panic!("unexpected error {:?}", e),
                     }
                 }
@@ -290,6 +297,95 @@ fn no_seek_unit_enum() {
     }
 }
 
+#[cfg(feature = "std")]
+#[test]
+fn source() {
+    use binrw::{error::ContextExt, BinRead};
+    use std::error::Error as _;
+
+    #[derive(Debug)]
+    struct Oops;
+    impl core::fmt::Display for Oops {
+        fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+            write!(f, "Oops")
+        }
+    }
+    impl std::error::Error for Oops {}
+
+    let err = Error::Custom {
+        pos: 0,
+        err: Box::new(Box::<dyn std::error::Error + Send + Sync>::from(Oops)),
+    }
+    .with_message("nested oops");
+    let root = err.source().unwrap().downcast_ref::<Error>().unwrap();
+    assert!(matches!(root, Error::Custom { .. }));
+    assert!(root.source().unwrap().is::<Oops>());
+
+    let err = Error::Custom {
+        pos: 0,
+        err: Box::new(Oops),
+    };
+    assert!(err.source().is_none());
+
+    let mut err = Error::custom(0, Oops);
+    assert!(err.source().unwrap().is::<Oops>());
+    assert!(err.custom_err::<Oops>().is_some());
+    assert_eq!(err.to_string(), "Oops at 0x0");
+    match &mut err {
+        Error::Custom { err, .. } => {
+            assert!(err.is::<Oops>());
+            assert!(err.downcast_mut::<Oops>().is_some());
+            assert!(err.downcast_mut::<i32>().is_none());
+        }
+        _ => unreachable!(),
+    }
+    match err {
+        Error::Custom { err, .. } => match err.downcast::<i32>() {
+            Ok(_) => panic!("downcast to wrong type"),
+            Err(err) => assert!(err.downcast::<Oops>().is_ok()),
+        },
+        _ => unreachable!(),
+    }
+
+    #[derive(BinRead, Debug)]
+    struct Test {
+        #[br(assert(_value == 0, Oops))]
+        _value: u8,
+    }
+
+    let err = Test::read_le(&mut binrw::io::Cursor::new(b"\x01")).unwrap_err();
+    assert!(err.source().unwrap().is::<Oops>());
+
+    let err = Error::Io(binrw::io::Error::new(
+        binrw::io::ErrorKind::UnexpectedEof,
+        Oops,
+    ));
+    assert_eq!(err.source().unwrap().to_string(), "Oops");
+
+    let err = Error::AssertFail {
+        pos: 0,
+        message: "Oops".into(),
+    };
+    assert!(err.source().is_none());
+}
+
+#[test]
+fn pos() {
+    use binrw::error::ContextExt;
+
+    let err = Error::AssertFail {
+        pos: 0x42,
+        message: "Oops".into(),
+    };
+    assert_eq!(err.pos(), Some(0x42));
+    assert_eq!(err.with_message("nested oops").pos(), Some(0x42));
+    assert_eq!(Error::NoVariantMatch { pos: 3 }.pos(), Some(3));
+    assert_eq!(
+        Error::Io(binrw::io::ErrorKind::UnexpectedEof.into()).pos(),
+        None
+    );
+}
+
 #[test]
 fn show_backtrace() {
     use alloc::borrow::Cow;
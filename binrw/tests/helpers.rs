@@ -0,0 +1,297 @@
+use binrw::{
+    helpers::{
+        read_f16, read_i24, read_i48, read_sleb128, read_u24, read_u48, read_uleb128, read_vlq,
+        read_zigzag_leb128, write_f16, write_i24, write_i48, write_sleb128, write_u24, write_u48,
+        write_uleb128, write_vlq, write_zigzag_leb128, zigzag_decode, zigzag_encode,
+    },
+    io::Cursor,
+    Endian, Error,
+};
+
+fn to_f16(value: f32) -> u16 {
+    let mut out = Cursor::new(Vec::new());
+    write_f16(&value, &mut out, Endian::Little, ()).unwrap();
+    u16::from_le_bytes(out.into_inner().try_into().unwrap())
+}
+
+fn from_f16(value: u16) -> f32 {
+    read_f16(&mut Cursor::new(value.to_be_bytes()), Endian::Big, ()).unwrap()
+}
+
+#[test]
+fn f16_read() {
+    assert_eq!(from_f16(0x0000).to_bits(), 0.0f32.to_bits());
+    assert_eq!(from_f16(0x8000).to_bits(), (-0.0f32).to_bits());
+    assert_eq!(from_f16(0x3c00), 1.0);
+    assert_eq!(from_f16(0xc000), -2.0);
+    assert_eq!(from_f16(0x7bff), 65504.0);
+    assert_eq!(from_f16(0x0400), 2.0f32.powi(-14));
+    assert_eq!(from_f16(0x0001), 2.0f32.powi(-24));
+    assert_eq!(from_f16(0x83ff), -1023.0 * 2.0f32.powi(-24));
+    assert_eq!(from_f16(0x7c00), f32::INFINITY);
+    assert_eq!(from_f16(0xfc00), f32::NEG_INFINITY);
+    assert!(from_f16(0x7e00).is_nan());
+}
+
+#[test]
+fn f16_write() {
+    assert_eq!(to_f16(0.0), 0x0000);
+    assert_eq!(to_f16(-0.0), 0x8000);
+    assert_eq!(to_f16(1.0), 0x3c00);
+    assert_eq!(to_f16(-2.0), 0xc000);
+    assert_eq!(to_f16(65504.0), 0x7bff);
+    assert_eq!(to_f16(65520.0), 0x7c00);
+    assert_eq!(to_f16(1e10), 0x7c00);
+    assert_eq!(to_f16(2.0f32.powi(-14)), 0x0400);
+    assert_eq!(to_f16(2.0f32.powi(-24)), 0x0001);
+    assert_eq!(to_f16(2.0f32.powi(-25)), 0x0000);
+    assert_eq!(to_f16(3.0 * 2.0f32.powi(-26)), 0x0001);
+    assert_eq!(to_f16(1e-10), 0x0000);
+    assert_eq!(to_f16(f32::INFINITY), 0x7c00);
+    assert_eq!(to_f16(f32::NEG_INFINITY), 0xfc00);
+    assert_eq!(to_f16(f32::NAN) & 0x7e00, 0x7e00);
+
+    // Ties round to even
+    assert_eq!(to_f16(1.0 + 2.0f32.powi(-11)), 0x3c00);
+    assert_eq!(to_f16(1.0 + 3.0 * 2.0f32.powi(-11)), 0x3c02);
+    // Rounding carries into the exponent
+    assert_eq!(to_f16(2.0 - 2.0f32.powi(-12)), 0x4000);
+}
+
+#[test]
+fn f16_round_trip() {
+    for half in (0..=u16::MAX).filter(|h| h & 0x7c00 != 0x7c00 || h & 0x3ff == 0) {
+        assert_eq!(to_f16(from_f16(half)), half);
+    }
+}
+
+#[test]
+fn int24() {
+    let read_i = |data: &[u8]| read_i24(&mut Cursor::new(data), Endian::Big, ()).unwrap();
+    assert_eq!(read_i(b"\x7f\xff\xff"), 0x7f_ffff);
+    assert_eq!(read_i(b"\x80\0\0"), -0x80_0000);
+    assert_eq!(read_i(b"\xff\xff\xff"), -1);
+    assert_eq!(
+        read_u24(&mut Cursor::new(b"\xff\xff\xff"), Endian::Little, ()).unwrap(),
+        0xff_ffff
+    );
+
+    let write_i = |value: i32| {
+        let mut out = Cursor::new(Vec::new());
+        write_i24(&value, &mut out, Endian::Little, ()).map(|()| out.into_inner())
+    };
+    assert_eq!(write_i(-0x80_0000).unwrap(), b"\0\0\x80");
+    assert_eq!(write_i(0x7f_ffff).unwrap(), b"\xff\xff\x7f");
+    assert!(write_i(-0x80_0001).is_err());
+    assert!(write_i(0x80_0000).is_err());
+
+    let mut out = Cursor::new(Vec::new());
+    assert!(matches!(
+        write_u24(&0x100_0000, &mut out, Endian::Big, ()),
+        Err(Error::AssertFail { pos: 0, .. })
+    ));
+    assert!(out.into_inner().is_empty());
+}
+
+#[test]
+fn int48() {
+    let read_i = |data: &[u8]| read_i48(&mut Cursor::new(data), Endian::Big, ()).unwrap();
+    assert_eq!(read_i(b"\x7f\xff\xff\xff\xff\xff"), (1 << 47) - 1);
+    assert_eq!(read_i(b"\x80\0\0\0\0\0"), -(1 << 47));
+    assert_eq!(
+        read_u48(
+            &mut Cursor::new(b"\xff\xff\xff\xff\xff\xff"),
+            Endian::Big,
+            ()
+        )
+        .unwrap(),
+        (1 << 48) - 1
+    );
+
+    let write_i = |value: i64| {
+        let mut out = Cursor::new(Vec::new());
+        write_i48(&value, &mut out, Endian::Big, ()).map(|()| out.into_inner())
+    };
+    assert_eq!(write_i(-(1 << 47)).unwrap(), b"\x80\0\0\0\0\0");
+    assert_eq!(write_i(-1).unwrap(), b"\xff\xff\xff\xff\xff\xff");
+    assert!(write_i(1 << 47).is_err());
+
+    let mut out = Cursor::new(Vec::new());
+    write_u48(&((1 << 48) - 1), &mut out, Endian::Big, ()).unwrap();
+    assert_eq!(out.into_inner(), b"\xff\xff\xff\xff\xff\xff");
+    assert!(write_u48(&(1 << 48), &mut Cursor::new(Vec::new()), Endian::Big, ()).is_err());
+}
+
+#[test]
+fn leb128() {
+    for (value, bytes) in [
+        (0, &b"\0"[..]),
+        (0x7f, b"\x7f"),
+        (0x80, b"\x80\x01"),
+        (624_485, b"\xe5\x8e\x26"),
+        (u64::MAX, b"\xff\xff\xff\xff\xff\xff\xff\xff\xff\x01"),
+    ] {
+        assert_eq!(
+            read_uleb128(&mut Cursor::new(bytes), Endian::Little, ()).unwrap(),
+            value
+        );
+        let mut out = Cursor::new(Vec::new());
+        write_uleb128(&value, &mut out, Endian::Big, ()).unwrap();
+        assert_eq!(out.into_inner(), bytes);
+    }
+
+    for (value, bytes) in [
+        (0, &b"\0"[..]),
+        (-1, b"\x7f"),
+        (63, b"\x3f"),
+        (64, b"\xc0\0"),
+        (-64, b"\x40"),
+        (-65, b"\xbf\x7f"),
+        (-123_456, b"\xc0\xbb\x78"),
+        (i64::MAX, b"\xff\xff\xff\xff\xff\xff\xff\xff\xff\0"),
+        (i64::MIN, b"\x80\x80\x80\x80\x80\x80\x80\x80\x80\x7f"),
+    ] {
+        assert_eq!(
+            read_sleb128(&mut Cursor::new(bytes), Endian::Little, ()).unwrap(),
+            value
+        );
+        let mut out = Cursor::new(Vec::new());
+        write_sleb128(&value, &mut out, Endian::Big, ()).unwrap();
+        assert_eq!(out.into_inner(), bytes);
+    }
+
+    // Non-minimal encodings are accepted
+    assert_eq!(
+        read_uleb128(&mut Cursor::new(b"\x81\x80\0"), Endian::Little, ()).unwrap(),
+        1
+    );
+
+    assert!(matches!(
+        read_uleb128(
+            &mut Cursor::new(b"\xff\xff\xff\xff\xff\xff\xff\xff\xff\x02"),
+            Endian::Little,
+            ()
+        ),
+        Err(Error::AssertFail { pos: 0, .. })
+    ));
+    assert!(matches!(
+        read_sleb128(
+            &mut Cursor::new(b"\x80\x80\x80\x80\x80\x80\x80\x80\x80\x01"),
+            Endian::Little,
+            ()
+        ),
+        Err(Error::AssertFail { pos: 0, .. })
+    ));
+    assert!(read_uleb128(&mut Cursor::new(b"\x80"), Endian::Little, ())
+        .unwrap_err()
+        .is_eof());
+}
+
+#[test]
+fn vlq() {
+    for (value, bytes) in [
+        (0, &b"\0"[..]),
+        (0x40, b"\x40"),
+        (0x7f, b"\x7f"),
+        (0x80, b"\x81\0"),
+        (0x2000, b"\xc0\0"),
+        (0x1f_ffff, b"\xff\xff\x7f"),
+        (0x0fff_ffff, b"\xff\xff\xff\x7f"),
+        (u64::MAX, b"\x81\xff\xff\xff\xff\xff\xff\xff\xff\x7f"),
+    ] {
+        assert_eq!(
+            read_vlq(&mut Cursor::new(bytes), Endian::Big, ()).unwrap(),
+            value
+        );
+        let mut out = Cursor::new(Vec::new());
+        write_vlq(&value, &mut out, Endian::Little, ()).unwrap();
+        assert_eq!(out.into_inner(), bytes);
+    }
+
+    assert!(matches!(
+        read_vlq(
+            &mut Cursor::new(b"\x82\x80\x80\x80\x80\x80\x80\x80\x80\0"),
+            Endian::Big,
+            ()
+        ),
+        Err(Error::AssertFail { pos: 0, .. })
+    ));
+}
+
+#[test]
+fn zigzag() {
+    for (value, encoded) in [
+        (0, 0),
+        (-1, 1),
+        (1, 2),
+        (-2, 3),
+        (i64::MAX, u64::MAX - 1),
+        (i64::MIN, u64::MAX),
+    ] {
+        assert_eq!(zigzag_encode(value), encoded);
+        assert_eq!(zigzag_decode(encoded), value);
+    }
+
+    assert_eq!(
+        read_zigzag_leb128(&mut Cursor::new(b"\xac\x02"), Endian::Little, ()).unwrap(),
+        150
+    );
+    let mut out = Cursor::new(Vec::new());
+    write_zigzag_leb128(&-150, &mut out, Endian::Little, ()).unwrap();
+    assert_eq!(out.into_inner(), b"\xab\x02");
+}
+
+#[test]
+fn read_to_end() {
+    let mut data = Cursor::new(b"\x01\x02\x03");
+    data.set_position(1);
+    assert_eq!(
+        binrw::helpers::read_to_end(&mut data, Endian::Little, ()).unwrap(),
+        b"\x02\x03"
+    );
+    assert!(binrw::helpers::read_to_end(&mut data, Endian::Little, ())
+        .unwrap()
+        .is_empty());
+}
+
+#[test]
+fn stride() {
+    use binrw::helpers::{stride, write_stride};
+
+    let parse = stride::<_, u16, _, Vec<u16>>(3);
+    let mut data = Cursor::new(b"\x01\0\xff\x02\0\xff");
+    assert_eq!(
+        parse(&mut data, Endian::Little, binrw::args! { count: 2 }).unwrap(),
+        [1, 2]
+    );
+    assert_eq!(data.position(), 6);
+
+    let parse = stride::<_, u32, _, Vec<u32>>(2);
+    assert!(matches!(
+        parse(
+            &mut Cursor::new(b"\0\0\0\0"),
+            Endian::Little,
+            binrw::args! { count: 1 }
+        ),
+        Err(Error::AssertFail { pos: 0, .. })
+    ));
+
+    let parse = stride::<_, u8, _, Vec<u8>>(u64::MAX);
+    let mut data = Cursor::new(b"\0\0");
+    data.set_position(1);
+    assert!(matches!(
+        parse(&mut data, Endian::Little, binrw::args! { count: 1 }),
+        Err(Error::AssertFail { pos: 1, .. })
+    ));
+
+    let write = write_stride::<_, [u16], _, _>(3);
+    let mut out = Cursor::new(Vec::new());
+    write(&[1, 2][..], &mut out, Endian::Big, ()).unwrap();
+    assert_eq!(out.into_inner(), b"\0\x01\0\0\x02\0");
+
+    let write = write_stride::<_, [u16], _, _>(1);
+    assert!(matches!(
+        write(&[1][..], &mut Cursor::new(Vec::new()), Endian::Big, ()),
+        Err(Error::AssertFail { pos: 0, .. })
+    ));
+}
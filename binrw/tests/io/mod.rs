@@ -4,3 +4,4 @@ mod bufreader;
 mod no_std;
 mod seek;
 mod take_seek;
+mod transform;
@@ -1,6 +1,6 @@
 #![allow(clippy::seek_to_start_instead_of_rewind)]
-use binrw::io::{Cursor, Read, Seek, SeekFrom};
-use std::io::{Read as StdReadExt, Seek as StdSeekExt};
+use binrw::io::{Cursor, Read, Seek, SeekFrom, Write};
+use std::io::{Read as StdReadExt, Seek as StdSeekExt, Write as StdWriteExt};
 
 #[test]
 fn cursor_test() {
@@ -41,3 +41,15 @@ fn cursor_test() {
     assert_eq!(test.read(&mut buf).unwrap(), test2.read(&mut buf2).unwrap());
     assert_eq!(buf, buf2);
 }
+
+#[test]
+fn cursor_array_write() {
+    let mut test = Cursor::new([0u8; 4]);
+    let mut test2 = std::io::Cursor::new([0u8; 4]);
+
+    assert_eq!(test.write(b"ab").unwrap(), test2.write(b"ab").unwrap());
+    assert_eq!(test.write(b"cde").unwrap(), test2.write(b"cde").unwrap());
+    assert_eq!(test.write(b"f").unwrap(), test2.write(b"f").unwrap());
+    assert_eq!(test.position(), test2.position());
+    assert_eq!(test.into_inner(), test2.into_inner());
+}
@@ -41,6 +41,43 @@ fn read() {
     assert_eq!(stream.into_inner(), &[]);
 }
 
+#[test]
+fn read_forward_seek() {
+    use binrw::{BinRead, BinReaderExt};
+
+    let mut stream = NoSeek::with_forward_seek(b"helloworld".as_slice());
+    let mut buf = [0; 3];
+
+    assert_eq!(stream.seek(SeekFrom::Start(2)).unwrap(), 2);
+    stream.read_exact(&mut buf).unwrap();
+    assert_eq!(&buf, b"llo");
+    assert_eq!(stream.seek(SeekFrom::Current(2)).unwrap(), 7);
+    assert_eq!(stream.stream_position().unwrap(), 7);
+    stream.seek(SeekFrom::Start(6)).unwrap_err();
+    stream.seek(SeekFrom::Current(-1)).unwrap_err();
+    stream.seek(SeekFrom::End(0)).unwrap_err();
+    stream.read_exact(&mut buf).unwrap();
+    assert_eq!(&buf, b"rld");
+    assert_eq!(
+        stream.seek(SeekFrom::Current(1)).unwrap_err().kind(),
+        binrw::io::ErrorKind::UnexpectedEof
+    );
+
+    #[derive(BinRead)]
+    #[br(big)]
+    struct Test {
+        a: u8,
+        #[br(pad_before = 300)]
+        b: u16,
+    }
+
+    let data = [1; 303];
+    let test = NoSeek::with_forward_seek(data.as_slice())
+        .read_be::<Test>()
+        .unwrap();
+    assert_eq!((test.a, test.b), (1, 0x101));
+}
+
 #[test]
 #[cfg(feature = "std")]
 fn read_vectored() {
@@ -114,3 +151,44 @@ fn write_vectored() {
     assert_eq!(stream.get_ref(), b"aaaaaaaaaa");
     assert_eq!(stream.stream_position().unwrap(), 10);
 }
+
+#[test]
+fn read_unread_one_byte() {
+    use binrw::{BinRead, BinReaderExt};
+
+    let mut stream = NoSeek::new(b"abc".as_slice());
+    let mut buf = [0; 2];
+
+    stream.read_exact(&mut buf).unwrap();
+    assert_eq!(stream.seek(SeekFrom::Current(-1)).unwrap(), 1);
+    stream.seek(SeekFrom::Current(-1)).unwrap_err();
+    stream.read_exact(&mut buf).unwrap();
+    assert_eq!(&buf, b"bc");
+    assert_eq!(stream.seek(SeekFrom::Start(2)).unwrap(), 2);
+    let mut rest = Vec::new();
+    stream.read_to_end(&mut rest).unwrap();
+    assert_eq!(rest, b"c");
+    assert_eq!(stream.stream_position().unwrap(), 3);
+
+    let mut stream = NoSeek::with_forward_seek(b"abcd".as_slice());
+    stream.read_exact(&mut buf[..1]).unwrap();
+    stream.seek(SeekFrom::Current(-1)).unwrap();
+    assert_eq!(stream.seek(SeekFrom::Current(2)).unwrap(), 2);
+    stream.seek(SeekFrom::Current(-1)).unwrap_err();
+    stream.read_exact(&mut buf).unwrap();
+    assert_eq!(&buf, b"cd");
+
+    #[derive(BinRead)]
+    struct Test {
+        a: u8,
+        #[br(if_not_eof)]
+        b: Option<u8>,
+        #[br(if_not_eof)]
+        c: Option<u8>,
+    }
+
+    let test = NoSeek::new(b"\x01\x02".as_slice())
+        .read_le::<Test>()
+        .unwrap();
+    assert_eq!((test.a, test.b, test.c), (1, Some(2), None));
+}
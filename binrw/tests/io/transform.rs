@@ -0,0 +1,32 @@
+use binrw::io::{Cursor, Read, Seek, SeekFrom, TransformReader};
+
+#[test]
+fn transform_reader() {
+    let mut data = Cursor::new(b"\xffabcdef");
+    data.seek(SeekFrom::Start(1)).unwrap();
+    let mut stream = TransformReader::new(&mut data, |offset, byte| {
+        byte.wrapping_sub(u8::try_from(offset).unwrap())
+    });
+    let mut buf = [0; 3];
+
+    assert_eq!(stream.stream_position().unwrap(), 1);
+    stream.read_exact(&mut buf).unwrap();
+    assert_eq!(&buf, b"aaa");
+    assert_eq!(stream.seek(SeekFrom::Current(1)).unwrap(), 5);
+    assert_eq!(stream.read(&mut buf).unwrap(), 2);
+    assert_eq!(&buf[..2], b"aa");
+    assert_eq!(stream.read(&mut buf).unwrap(), 0);
+
+    stream.seek(SeekFrom::Start(2)).unwrap();
+    stream.read_exact(&mut buf[..1]).unwrap();
+    assert_eq!(buf[0], b'a');
+
+    stream.seek(SeekFrom::Start(0)).unwrap();
+    assert_eq!(
+        stream.read(&mut buf).unwrap_err().kind(),
+        binrw::io::ErrorKind::InvalidInput
+    );
+
+    stream.get_mut().set_position(0);
+    assert_eq!(stream.into_inner().position(), 0);
+}
@@ -1,7 +1,7 @@
 extern crate alloc;
 
 use alloc::format;
-use binrw::{io::Cursor, BinRead, BinReaderExt, PosValue};
+use binrw::{io::Cursor, BinRead, BinReaderExt, BinWrite, PosValue};
 
 #[test]
 fn pos_value() {
@@ -14,6 +14,7 @@ fn pos_value() {
     let mut val = Cursor::new(b"\xFF\xFE\xFD").read_be::<MyType>().unwrap();
     assert_eq!(val.a, 0xFFFE);
     assert_eq!(val.b.pos, 2);
+    assert_eq!(val.b.end, 3);
     assert_eq!(*val.b, 0xFD);
     assert_eq!(val.b, 0xFDu8);
 
@@ -23,4 +24,27 @@ fn pos_value() {
     let clone = val.b.clone();
     assert_eq!(*clone, *val.b);
     assert_eq!(clone.pos, val.b.pos);
+    assert_eq!(clone.end, val.b.end);
+}
+
+#[test]
+fn pos_value_args_and_write() {
+    #[derive(BinRead, BinWrite)]
+    #[brw(big)]
+    struct MyType {
+        len: u8,
+        #[br(count = len)]
+        data: PosValue<Vec<u16>>,
+        tail: PosValue<u8>,
+    }
+
+    let data = b"\x02\0\x01\0\x02\x03";
+    let val = Cursor::new(data).read_be::<MyType>().unwrap();
+    assert_eq!((val.data.pos, val.data.end), (1, 5));
+    assert_eq!(*val.data, [1, 2]);
+    assert_eq!((val.tail.pos, val.tail.end), (5, 6));
+
+    let mut out = Cursor::new(Vec::new());
+    val.write(&mut out).unwrap();
+    assert_eq!(out.into_inner(), data);
 }
@@ -36,6 +36,38 @@ fn BinReaderExt() {
     );
 }
 
+#[test]
+fn iter() {
+    let mut data = binrw::io::Cursor::new(b"\x01\x02\x03\x04");
+    assert_eq!(
+        data.iter_be::<u16>()
+            .collect::<Result<Vec<_>, _>>()
+            .unwrap(),
+        vec![0x102, 0x304]
+    );
+    assert!(data.iter_le::<u16>().next().is_none());
+
+    data.set_position(1);
+    let mut iter = data.iter_le::<u16>();
+    assert_eq!(iter.next().unwrap().unwrap(), 0x302);
+    assert!(iter.next().unwrap().unwrap_err().is_eof());
+    assert!(iter.next().is_none());
+
+    data.set_position(0);
+    let mut iter =
+        data.iter_type_args::<Vec<u8>>(binrw::Endian::Big, VecArgs::builder().count(3).finalize());
+    assert_eq!(iter.next().unwrap().unwrap(), [1, 2, 3]);
+    assert!(iter.next().unwrap().is_err());
+
+    let mut data = binrw::io::NoSeek::new(&b"\x01\x02\x03\x04"[..]);
+    assert_eq!(
+        data.iter_be::<u16>()
+            .collect::<Result<Vec<_>, _>>()
+            .unwrap(),
+        vec![0x102, 0x304]
+    );
+}
+
 #[test]
 #[allow(non_snake_case)]
 fn BinWriterExt() {
@@ -152,3 +152,179 @@ fn null_wide_string_round_trip() {
 
     assert_eq!(&s2.to_string(), data);
 }
+
+#[test]
+fn pascal_strings() {
+    use binrw::{io::Cursor, BinReaderExt, PascalString};
+
+    let mut data = Cursor::new(b"\0\x05hello\x04bad\xfe\0\0\0\0");
+
+    assert_eq!(
+        data.read_be::<PascalString<u16>>().unwrap().to_string(),
+        "hello"
+    );
+
+    assert_eq!(
+        format!("{:?}", data.read_be::<PascalString>().unwrap()),
+        "PascalString(\"bad\u{FFFD}\")"
+    );
+
+    assert_eq!(data.read_le::<PascalString<u32>>().unwrap().len(), 0);
+
+    Cursor::new(b"\x04abc")
+        .read_be::<PascalString>()
+        .expect_err("accepted truncated data");
+
+    // Default/Deref/DerefMut
+    let mut s = PascalString::<u8>::default();
+    s.extend_from_slice(b"hey");
+    assert_eq!(&s[0..2], b"he");
+
+    // Clone/TryFrom
+    let t = String::try_from(s.clone()).unwrap();
+    assert_eq!(t, "hey");
+    s.extend_from_slice(b"\xe2");
+    String::try_from(s).expect_err("accepted bad data");
+
+    // From
+    let s = PascalString::<u8>::from(t.clone());
+    assert_eq!(Vec::from(s), t.as_bytes());
+}
+
+#[test]
+fn pascal_string_round_trip() {
+    use binrw::{io::Cursor, BinReaderExt, BinWriterExt, PascalString};
+
+    let data = "test test test";
+    let s = PascalString::<u16>::from(data);
+
+    let mut x = Cursor::new(Vec::new());
+    x.write_le(&s).unwrap();
+    assert_eq!(&x.get_ref()[..2], b"\x0e\0");
+
+    let s2: PascalString<u16> = Cursor::new(x.into_inner()).read_le().unwrap();
+
+    assert_eq!(&s2.to_string(), data);
+}
+
+#[test]
+fn pascal_string_too_long() {
+    use binrw::{io::Cursor, BinWriterExt, PascalString};
+
+    let s = PascalString::<u8>::new(vec![0; 256]);
+    let mut x = Cursor::new(Vec::new());
+    assert!(matches!(
+        x.write_be(&s),
+        Err(binrw::Error::AssertFail { pos: 0, .. })
+    ));
+}
+
+#[test]
+fn fixed_strings() {
+    use binrw::{io::Cursor, BinReaderExt, FixedString};
+
+    let mut data = Cursor::new(b"abc\0\0\0de\0\xfegarbage");
+
+    let s = data.read_be::<FixedString<6>>().unwrap();
+    assert_eq!(&s.0, b"abc\0\0\0");
+    assert_eq!(s.trimmed(), b"abc");
+    assert_eq!(s.terminated(), b"abc");
+
+    let s = data.read_be::<FixedString<11>>().unwrap();
+    assert_eq!(s.trimmed(), b"de\0\xfegarbage");
+    assert_eq!(s.terminated(), b"de");
+    assert_eq!(format!("{:?}", s), "FixedString(\"de\\0\u{FFFD}garbage\")");
+
+    Cursor::new(b"abc")
+        .read_be::<FixedString<4>>()
+        .expect_err("accepted truncated data");
+
+    // Default/Deref/DerefMut
+    let mut s = FixedString::<4, b' '>::default();
+    assert_eq!(&*s, b"    ");
+    s[..3].copy_from_slice(b"hey");
+    assert_eq!(s.trimmed(), b"hey");
+
+    // Clone/TryFrom
+    let t = String::try_from(s).unwrap();
+    assert_eq!(t, "hey");
+    s[3] = 0xe2;
+    String::try_from(s).expect_err("accepted bad data");
+
+    // new/From
+    assert!(FixedString::<2>::new(b"abc").is_none());
+    let s = FixedString::<8, b'.'>::new(t.as_bytes()).unwrap();
+    assert_eq!(&s.0, b"hey.....");
+    assert_eq!(Vec::from(s), t.as_bytes());
+}
+
+#[test]
+fn fixed_string_round_trip() {
+    use binrw::{io::Cursor, BinReaderExt, BinWriterExt, FixedString};
+
+    let data = "test";
+    let s = FixedString::<8, b' '>::new(data.as_bytes()).unwrap();
+
+    let mut x = Cursor::new(Vec::new());
+    x.write_be(&s).unwrap();
+    assert_eq!(x.get_ref(), b"test    ");
+
+    let s2: FixedString<8, b' '> = Cursor::new(x.into_inner()).read_be().unwrap();
+
+    assert_eq!(&s2.to_string(), data);
+}
+
+#[test]
+fn null_string_max_len() {
+    use binrw::{io::Cursor, BinReaderExt, NullString, NullStringArgs, NullWideString};
+
+    let args = NullStringArgs::builder().max_len(Some(3)).finalize();
+    let mut data = Cursor::new(b"abc\0abcd\0");
+    assert_eq!(
+        data.read_le_args::<NullString>(args).unwrap().to_string(),
+        "abc"
+    );
+    assert!(matches!(
+        data.read_le_args::<NullString>(args),
+        Err(binrw::Error::AssertFail { pos: 4, .. })
+    ));
+
+    let args = NullStringArgs::builder()
+        .max_len(Some(2))
+        .truncate(true)
+        .finalize();
+    let mut data = Cursor::new(b"abcd\0e\0");
+    assert_eq!(
+        data.read_le_args::<NullString>(args).unwrap().to_string(),
+        "ab"
+    );
+    assert_eq!(
+        data.read_le_args::<NullString>(args).unwrap().to_string(),
+        "e"
+    );
+
+    let mut data = Cursor::new(b"a\0b\0c\0\0\0");
+    assert_eq!(
+        data.read_le_args::<NullWideString>(args)
+            .unwrap()
+            .to_string(),
+        "ab"
+    );
+    let mut data = Cursor::new(b"\0\0a\0b\0c\0\0\0");
+    data.set_position(2);
+    assert!(matches!(
+        data.read_le_args::<NullWideString>(NullStringArgs {
+            max_len: Some(2),
+            truncate: false,
+        }),
+        Err(binrw::Error::AssertFail { pos: 2, .. })
+    ));
+
+    assert!(Cursor::new(b"unterminated")
+        .read_le_args::<NullString>(NullStringArgs {
+            max_len: Some(4),
+            truncate: true,
+        })
+        .unwrap_err()
+        .is_eof());
+}
@@ -0,0 +1,119 @@
+#![cfg(feature = "std")]
+
+use binrw::{
+    io::Cursor,
+    time::{DosDateTime, FileTime, UnixTime32},
+    BinReaderExt, BinWrite,
+};
+use std::time::{Duration, UNIX_EPOCH};
+
+#[test]
+fn unix_time() {
+    let time: UnixTime32 = Cursor::new(b"\x65\x53\xf1\x00").read_be().unwrap();
+    assert_eq!(time.0, 0x6553_f100);
+    assert_eq!(
+        time.to_system_time(),
+        UNIX_EPOCH + Duration::from_secs(1_700_000_000)
+    );
+    assert_eq!(
+        UnixTime32::from_system_time(time.to_system_time() + Duration::from_millis(999)),
+        Some(time)
+    );
+    assert_eq!(
+        UnixTime32::from_system_time(UNIX_EPOCH - Duration::from_secs(1)),
+        None
+    );
+    assert_eq!(
+        UnixTime32::from_system_time(UNIX_EPOCH + Duration::from_secs(1 << 32)),
+        None
+    );
+
+    let mut out = Cursor::new(Vec::new());
+    time.write_le(&mut out).unwrap();
+    assert_eq!(out.into_inner(), b"\x00\xf1\x53\x65");
+}
+
+#[test]
+fn file_time() {
+    let time: FileTime = Cursor::new(b"\x01\x80\x3e\xd5\xde\xb1\x9d\x01")
+        .read_le()
+        .unwrap();
+    assert_eq!(
+        time.to_system_time(),
+        Some(UNIX_EPOCH + Duration::from_nanos(100))
+    );
+    assert_eq!(
+        FileTime::from_system_time(UNIX_EPOCH + Duration::from_nanos(199)),
+        Some(time)
+    );
+
+    let before_unix = FileTime(1);
+    assert_eq!(
+        FileTime::from_system_time(before_unix.to_system_time().unwrap()),
+        Some(before_unix)
+    );
+    assert_eq!(FileTime(0).to_duration(), Duration::ZERO);
+    assert_eq!(
+        FileTime(12_345_678).to_duration(),
+        Duration::new(1, 234_567_800)
+    );
+    assert_eq!(FileTime::from_duration(Duration::MAX), None);
+
+    let mut out = Cursor::new(Vec::new());
+    time.write_be(&mut out).unwrap();
+    assert_eq!(out.into_inner(), b"\x01\x9d\xb1\xde\xd5\x3e\x80\x01");
+}
+
+#[test]
+fn dos_date_time() {
+    let time: DosDateTime = Cursor::new(b"\x2f\x7b\x21\x5a").read_le().unwrap();
+    assert_eq!(time, DosDateTime::new(2025, 1, 1, 15, 25, 31).unwrap());
+    assert_eq!(
+        time.to_system_time(),
+        Some(UNIX_EPOCH + Duration::from_secs(1_735_745_130))
+    );
+    assert_eq!(
+        DosDateTime::from_system_time(UNIX_EPOCH + Duration::from_secs(1_735_745_131)),
+        Some(time)
+    );
+
+    assert_eq!(
+        DosDateTime::new(1980, 1, 1, 0, 0, 0)
+            .unwrap()
+            .to_system_time(),
+        Some(UNIX_EPOCH + Duration::from_secs(315_532_800))
+    );
+    assert_eq!(
+        DosDateTime::new(2000, 2, 29, 23, 59, 58)
+            .unwrap()
+            .to_system_time(),
+        Some(UNIX_EPOCH + Duration::from_secs(951_868_798))
+    );
+    assert_eq!(
+        DosDateTime::from_system_time(UNIX_EPOCH + Duration::from_secs(951_868_799)),
+        DosDateTime::new(2000, 2, 29, 23, 59, 58)
+    );
+
+    assert!(DosDateTime::new(1979, 12, 31, 0, 0, 0).is_none());
+    assert!(DosDateTime::new(2108, 1, 1, 0, 0, 0).is_none());
+    assert!(DosDateTime::new(2000, 13, 1, 0, 0, 0).is_none());
+    assert!(DosDateTime::new(2000, 4, 31, 0, 0, 0).is_none());
+    assert!(DosDateTime::new(2001, 2, 29, 0, 0, 0).is_none());
+    assert!(DosDateTime::new(2100, 2, 29, 0, 0, 0).is_none());
+    assert!(DosDateTime::new(2000, 2, 30, 0, 0, 0).is_none());
+    assert!(DosDateTime::new(2024, 2, 29, 0, 0, 0).is_some());
+    assert!(DosDateTime::new(2000, 12, 31, 0, 0, 0).is_some());
+    assert!(DosDateTime {
+        time: 0,
+        date: (21 << 9) | (2 << 5) | 29
+    }
+    .to_system_time()
+    .is_none());
+    assert!(DosDateTime::new(2000, 1, 1, 24, 0, 0).is_none());
+    assert!(DosDateTime::default().to_system_time().is_none());
+    assert!(DosDateTime::from_system_time(UNIX_EPOCH).is_none());
+
+    let mut out = Cursor::new(Vec::new());
+    time.write_be(&mut out).unwrap();
+    assert_eq!(out.into_inner(), b"\x7b\x2f\x5a\x21");
+}
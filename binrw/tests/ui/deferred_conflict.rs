@@ -0,0 +1,9 @@
+use binrw::{BinWrite, FilePtr8};
+
+#[derive(BinWrite)]
+struct Test {
+    #[bw(deferred, calc = FilePtr8 { ptr: 0, value: 1 })]
+    a: FilePtr8<u8>,
+}
+
+fn main() {}
@@ -0,0 +1,9 @@
+use binrw::BinRead;
+
+#[derive(BinRead)]
+struct Struct {
+    #[br(warn(field == 0, std::io::Error::new(std::io::ErrorKind::Other, "oops")))]
+    field: i32,
+}
+
+fn main() {}
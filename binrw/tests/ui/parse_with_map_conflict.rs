@@ -0,0 +1,12 @@
+use binrw::{BinRead, BinResult};
+
+#[binrw::parser]
+fn parse() -> BinResult<Foo> {
+    Ok(Foo(0))
+}
+
+#[derive(BinRead)]
+#[br(parse_with = parse, map = |x: u8| Foo(x))]
+struct Foo(u8);
+
+fn main() {}
@@ -0,0 +1,42 @@
+use binrw::{
+    io::{Cursor, Seek, SeekFrom},
+    BinWrite, Endian, WriteHole,
+};
+
+#[test]
+fn write_hole() {
+    let mut out = Cursor::new(Vec::new());
+    0xFFu8.write_le(&mut out).unwrap();
+    let hole = WriteHole::<u16>::reserve(&mut out, Endian::Big, ()).unwrap();
+    assert_eq!(hole.pos(), 1);
+    assert_eq!(
+        format!("{:?}", hole),
+        "WriteHole { pos: 1, len: 2, endian: Big }"
+    );
+    [1u8, 2, 3].write_le(&mut out).unwrap();
+    hole.fill(&mut out, &0x0102, ()).unwrap();
+    assert_eq!(out.stream_position().unwrap(), 6);
+    assert_eq!(out.into_inner(), b"\xFF\x01\x02\x01\x02\x03");
+}
+
+#[test]
+fn write_hole_placeholder() {
+    let mut out = Cursor::new(Vec::new());
+    let hole = WriteHole::new(&mut out, Endian::Little, &0xAAAAu16, ()).unwrap();
+    assert_eq!(out.get_ref(), b"\xAA\xAA");
+    out.seek(SeekFrom::Start(3)).unwrap();
+    0xFFu8.write_le(&mut out).unwrap();
+    hole.fill(&mut out, &0x0102, ()).unwrap();
+    assert_eq!(out.stream_position().unwrap(), 4);
+    assert_eq!(out.into_inner(), b"\x02\x01\0\xFF");
+}
+
+#[test]
+fn write_hole_size_mismatch() {
+    let mut out = Cursor::new(Vec::new());
+    let hole = WriteHole::<Vec<u8>>::new(&mut out, Endian::Little, &vec![0; 2], ()).unwrap();
+    0xFFu8.write_le(&mut out).unwrap();
+    let error = hole.fill(&mut out, &vec![1, 2, 3], ()).unwrap_err();
+    assert!(matches!(error, binrw::Error::AssertFail { pos: 0, .. }));
+    assert_eq!(out.stream_position().unwrap(), 3);
+}
@@ -186,13 +186,18 @@ fn visit_expr_attributes(field: &StructField, visitor: &mut Visitor) {
 
     spans_from_exprs!(
         count,
+        stride,
         offset,
+        offset_map,
         pad_before,
         pad_after,
         align_before,
         align_after,
         seek_before,
-        pad_size_to
+        pad_size_to,
+        size,
+        fill,
+        set_endian
     );
 
     if let Some(condition) = field.if_cond.clone() {
@@ -208,7 +213,7 @@ fn visit_expr_attributes(field: &StructField, visitor: &mut Visitor) {
     }
 
     if let Some(magic) = field.magic.clone() {
-        visit!(magic.into_value().into_match_value());
+        visit!(magic.into_value().into_match_pattern());
     }
 
     if let CondEndian::Cond(_, expr) = &field.endian {
@@ -242,13 +247,15 @@ fn visit_expr_attributes(field: &StructField, visitor: &mut Visitor) {
         PassedArgs::None => (),
     }
 
-    if let FieldMode::Calc(expr) | FieldMode::TryCalc(expr) | FieldMode::Function(expr) =
-        &field.field_mode
+    if let FieldMode::Calc(expr)
+    | FieldMode::TryCalc(expr)
+    | FieldMode::Function(expr)
+    | FieldMode::Default(Some(expr)) = &field.field_mode
     {
         visit!(expr.clone());
     }
 
-    for assert in &field.assertions {
+    for assert in field.assertions.iter().chain(&field.warnings) {
         visit!(assert.condition.clone());
 
         let (AssertionError::Message(err) | AssertionError::Error(err)) = assert.consequent.clone();
@@ -391,10 +398,11 @@ fn is_keyword_ident(ident: &syn::Ident) -> bool {
 
         // binrw 'keywords'
         align_after, align_before, args, args_raw, assert, big, binread, br, brw, binwrite,
-        bw, calc, count, default, ignore, import, import_raw, is_big, is_little,
-        little, magic, map, offset, pad_after, pad_before, pad_size_to, parse_with,
-        pre_assert, repr, restore_position, return_all_errors,
-        return_unexpected_error, seek_before, temp, try_map, write_with
+        bw, calc, count, default, deferred, fill, if_not_eof, ignore, import, import_raw, is_big, is_little,
+        little, magic, map, offset, offset_from_start, offset_map, pad_after, pad_before,
+        pad_size_to, parse_with, pre_assert, repr, rest, restore_position, return_all_errors,
+        return_unexpected_error, seek_before, set_endian, size, stride, temp, try_map, warn,
+        write_with
     );
 
     is_keyword
@@ -5,7 +5,8 @@ mod write_options;
 
 use crate::{
     binrw::parser::{
-        Assert, AssertionError, CondEndian, Imports, Input, ParseResult, PassedArgs, StructField,
+        Assert, AssertionError, CondEndian, EnumVariant, Imports, Input, ParseResult, PassedArgs,
+        Struct, StructField,
     },
     named_args::{arg_type_name, derive_from_imports},
     util::{quote_spanned_any, IdentStr},
@@ -13,9 +14,9 @@ use crate::{
 use proc_macro2::{Span, TokenStream};
 use quote::{quote, quote_spanned, ToTokens};
 use sanitization::{
-    ARGS, ARGS_LIFETIME, ARGS_MACRO, ASSERT, ASSERT_ERROR_FN, BINREAD_TRAIT, BINWRITE_TRAIT,
-    BIN_ERROR, BIN_RESULT, ENDIAN_ENUM, OPT, POS, READER, READ_TRAIT, SEEK_TRAIT, TEMP, WRITER,
-    WRITE_TRAIT,
+    ANY_CUSTOM_ERR_TRAIT, ARGS, ARGS_LIFETIME, ARGS_MACRO, ASSERT, ASSERT_ERROR_FN, BINREAD_TRAIT,
+    BINWRITE_TRAIT, BIN_ERROR, BIN_RESULT, CUSTOM_ERR, ENDIAN_ENUM, OPT, POS, READER, READ_TRAIT,
+    SEEK_TRAIT, STD_CUSTOM_ERR_TRAIT, STRUCT_POS, TEMP, WRITER, WRITE_TRAIT,
 };
 use syn::{spanned::Spanned, DeriveInput, Ident, Type};
 
@@ -208,9 +209,10 @@ fn get_assertions(assertions: &[Assert]) -> impl Iterator<Item = TokenStream> +
          }| {
             let error_fn = match &consequent {
                 AssertionError::Message(message) => {
-                    quote! { #ASSERT_ERROR_FN::<_, fn() -> !>::Message(|| { #message }) }
+                    quote! { #ASSERT_ERROR_FN::<_, fn() -> #BIN_ERROR>::Message(|| { #message }) }
                 }
                 AssertionError::Error(error) => {
+                    let error = get_custom_err(POS, error);
                     quote! { #ASSERT_ERROR_FN::Error::<fn() -> &'static str, _>(|| { #error }) }
                 }
             };
@@ -271,23 +273,61 @@ fn get_endian(endian: &CondEndian) -> TokenStream {
     }
 }
 
+fn get_set_endian(set_endian: Option<&TokenStream>) -> Option<TokenStream> {
+    set_endian.map(|endian| {
+        quote_spanned! {endian.span()=>
+            #[allow(unused_variables)]
+            let #OPT: #ENDIAN_ENUM = #endian;
+        }
+    })
+}
+
+/// Returns true if any field uses `offset_from_start`, which needs the
+/// position of the object before any magic was read or written.
+fn uses_offset_from_start(input: &Input) -> bool {
+    let any_field = |st: &Struct| {
+        st.fields
+            .iter()
+            .any(|field| field.offset_from_start.is_some())
+    };
+
+    match input {
+        Input::Struct(st) => any_field(st),
+        Input::Enum(en) => en.variants.iter().any(|variant| match variant {
+            EnumVariant::Variant { options, .. } => any_field(options),
+            EnumVariant::Unit(_) => false,
+        }),
+        Input::UnitStruct(_) | Input::UnitOnlyEnum(_) => false,
+    }
+}
+
 fn get_map_err(pos: IdentStr, span: Span) -> TokenStream {
+    let custom_err = get_custom_err(pos, &quote! { e });
     quote_spanned_any! { span=>
-        .map_err(|e| {
-            #BIN_ERROR::Custom {
-                pos: #pos,
-                err: Box::new(e) as _,
-            }
-        })
+        .map_err(|e| { #custom_err })
+    }
+}
+
+/// Converts an error from a directive into an [`Error::Custom`], keeping it
+/// available from `source()` if it implements `std::error::Error`.
+fn get_custom_err(pos: IdentStr, error: &TokenStream) -> TokenStream {
+    quote_spanned_any! { error.span()=>
+        {
+            #[allow(unused_imports)]
+            use #STD_CUSTOM_ERR_TRAIT as _;
+            #[allow(unused_imports)]
+            use #ANY_CUSTOM_ERR_TRAIT as _;
+            (&mut #CUSTOM_ERR(Some(#error))).take_custom_err(#pos)
+        }
     }
 }
 
-fn get_passed_args(field: &StructField, stream: IdentStr) -> Option<TokenStream> {
+fn get_passed_args(field: &StructField, stream: IdentStr, for_write: bool) -> Option<TokenStream> {
     let args = &field.args;
     let span = args.span().unwrap_or_else(|| field.ty.span());
     match args {
         PassedArgs::Named(fields) => Some({
-            let extra_args = directives_to_args(field, stream);
+            let extra_args = directives_to_args(field, stream, for_write);
             quote_spanned_any! { span=>
                 #ARGS_MACRO! { #extra_args #(#fields, )* }
             }
@@ -295,7 +335,7 @@ fn get_passed_args(field: &StructField, stream: IdentStr) -> Option<TokenStream>
         PassedArgs::List(list) => Some(quote_spanned! {span=> (#(#list,)*) }),
         PassedArgs::Tuple(tuple) => Some(tuple.as_ref().clone()),
         PassedArgs::None => {
-            let extra_args = directives_to_args(field, stream);
+            let extra_args = directives_to_args(field, stream, for_write);
             (!extra_args.is_empty()).then(|| {
                 quote_spanned_any! { span=> #ARGS_MACRO! { #extra_args } }
             })
@@ -311,15 +351,18 @@ fn get_try_calc(pos: IdentStr, ty: &Type, calc: &TokenStream) -> TokenStream {
     }}
 }
 
-fn directives_to_args(field: &StructField, stream: IdentStr) -> TokenStream {
+fn directives_to_args(field: &StructField, stream: IdentStr, for_write: bool) -> TokenStream {
+    // When writing, `count` is checked against the length of the value instead
+    // of being passed as an argument
     let args = field
         .count
         .as_ref()
+        .filter(|_| !for_write)
         .map(|count| {
             quote_spanned_any! {count.span()=>
                 count: {
                     let #TEMP = #count;
-                    #[allow(clippy::useless_conversion)]
+                    #[allow(clippy::useless_conversion, clippy::unnecessary_fallible_conversions)]
                     usize::try_from(#TEMP).map_err(|_| {
                         extern crate alloc;
                         #BIN_ERROR::AssertFail {
@@ -337,11 +380,16 @@ fn directives_to_args(field: &StructField, stream: IdentStr) -> TokenStream {
             }
         })
         .into_iter()
-        .chain(
-            field
-                .offset
-                .as_ref()
-                .map(|offset| quote_spanned! { offset.span()=> offset: #offset }),
-        );
+        .chain(match (&field.offset, field.offset_from_start) {
+            (Some(offset), Some(())) => Some(quote_spanned! { offset.span()=>
+                offset: #STRUCT_POS + (#offset)
+            }),
+            (Some(offset), None) => Some(quote_spanned! { offset.span()=> offset: #offset }),
+            (None, Some(())) => Some(quote! { offset: #STRUCT_POS }),
+            (None, None) => None,
+        })
+        .chain(field.offset_map.as_ref().map(|offset_map| {
+            quote_spanned! { offset_map.span()=> offset_map: #offset_map }
+        }));
     quote! { #(#args,)* }
 }
@@ -2,14 +2,14 @@ mod r#enum;
 mod map;
 mod r#struct;
 
-use super::{get_assertions, get_destructured_imports};
+use super::{get_assertions, get_destructured_imports, uses_offset_from_start};
 use crate::{
     binrw::{
         codegen::{
             get_endian,
             sanitization::{
-                ARGS, ASSERT_MAGIC, MAP_READER_TYPE_HINT, OPT, POS, READER, RESTORE_POSITION,
-                SEEK_TRAIT,
+                ARGS, ASSERT_MAGIC, ASSERT_MAGIC_ONE_OF, MAP_READER_TYPE_HINT, OPT, POS, READER,
+                RESTORE_POSITION, SEEK_TRAIT, STRUCT_POS,
             },
         },
         parser::{Input, Magic, Map},
@@ -24,29 +24,33 @@ use syn::{spanned::Spanned, Ident};
 
 pub(crate) fn generate(input: &Input, derive_input: &syn::DeriveInput) -> TokenStream {
     let name = Some(&derive_input.ident);
-    let (inner, needs_rewind) = match input.map() {
-        Map::None => match input {
-            Input::UnitStruct(_) => (generate_unit_struct(input, name, None), false),
-            Input::Struct(s) => (generate_struct(input, name, s), true),
-            Input::Enum(e) => (generate_data_enum(input, name, e), false),
-            Input::UnitOnlyEnum(e) => (
-                generate_unit_enum(input, name, e),
-                e.map.as_repr().is_some(),
-            ),
-        },
-        Map::Try(map) => (map::generate_try_map(input, name, map), true),
-        Map::Map(map) => (map::generate_map(input, name, map), true),
-        Map::Repr(ty) => match input {
-            Input::UnitOnlyEnum(e) => (generate_unit_enum(input, name, e), true),
-            _ => (
-                map::generate_try_map(
-                    input,
-                    name,
-                    &quote! { <#ty as core::convert::TryInto<_>>::try_into },
+    let (inner, needs_rewind) = if let Some(parse_with) = input.parse_with() {
+        (map::generate_parse_with(input, parse_with), true)
+    } else {
+        match input.map() {
+            Map::None => match input {
+                Input::UnitStruct(_) => (generate_unit_struct(input, name, None), false),
+                Input::Struct(s) => (generate_struct(input, name, s), true),
+                Input::Enum(e) => (generate_data_enum(input, name, e), false),
+                Input::UnitOnlyEnum(e) => (
+                    generate_unit_enum(input, name, e),
+                    e.map.as_repr().is_some(),
                 ),
-                true,
-            ),
-        },
+            },
+            Map::Try(map) => (map::generate_try_map(input, name, map), true),
+            Map::Map(map) => (map::generate_map(input, name, map), true),
+            Map::Repr(ty) => match input {
+                Input::UnitOnlyEnum(e) => (generate_unit_enum(input, name, e), true),
+                _ => (
+                    map::generate_try_map(
+                        input,
+                        name,
+                        &quote! { <#ty as core::convert::TryInto<_>>::try_into },
+                    ),
+                    true,
+                ),
+            },
+        }
     };
 
     let reader_var = input.stream_ident_or(READER);
@@ -57,9 +61,16 @@ pub(crate) fn generate(input: &Input, derive_input: &syn::DeriveInput) -> TokenS
         }
     });
 
+    let struct_pos = uses_offset_from_start(input).then(|| {
+        quote! {
+            let #STRUCT_POS = #POS;
+        }
+    });
+
     quote! {
         let #reader_var = #READER;
         let #POS = #SEEK_TRAIT::stream_position(#reader_var)?;
+        #struct_pos
         (|| {
             #inner
         })()#rewind
@@ -155,9 +166,19 @@ fn get_magic(
     endian_var: impl ToTokens,
 ) -> Option<TokenStream> {
     magic.as_ref().map(|magic| {
-        let magic = magic.deref_value();
-        quote! {
-            #ASSERT_MAGIC(#reader_var, #magic, #endian_var)?;
+        if magic.is_single_value() {
+            let magic = magic.deref_value();
+            quote! {
+                #ASSERT_MAGIC(#reader_var, #magic, #endian_var)?;
+            }
+        } else {
+            let binding = magic
+                .binding()
+                .map_or_else(|| quote! { _ }, ToTokens::to_token_stream);
+            let magics = magic.deref_values();
+            quote! {
+                let #binding = #ASSERT_MAGIC_ONE_OF(#reader_var, &[#(#magics),*], #endian_var)?;
+            }
         }
     })
 }
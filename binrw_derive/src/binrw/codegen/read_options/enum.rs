@@ -4,13 +4,14 @@ use super::{
 };
 use crate::binrw::{
     codegen::sanitization::{
-        BACKTRACE_FRAME, BIN_ERROR, ERROR_BASKET, OPT, POS, READER, READ_METHOD,
+        BACKTRACE_FRAME, BIN_ERROR, ERROR_BASKET, OPT, POS, READER, READ_METHOD, RESTORE_POSITION,
         RESTORE_POSITION_VARIANT, TEMP, WITH_CONTEXT,
     },
-    parser::{Enum, EnumErrorMode, EnumVariant, Input, UnitEnumField, UnitOnlyEnum},
+    parser::{CondEndian, Enum, EnumErrorMode, EnumVariant, Input, UnitEnumField, UnitOnlyEnum},
 };
 use proc_macro2::TokenStream;
 use quote::quote;
+use std::collections::HashSet;
 use syn::Ident;
 
 pub(super) fn generate_unit_enum(
@@ -102,7 +103,7 @@ fn generate_unit_enum_magic(reader_var: &TokenStream, variants: &[UnitEnumField]
             let ident = &field.ident;
 
             if let Some(magic) = &field.magic {
-                let magic = magic.match_value();
+                let magic = magic.match_pattern();
                 let condition = if field.pre_assertions.is_empty() {
                     quote! { #magic }
                 } else {
@@ -182,6 +183,14 @@ pub(super) fn generate_data_enum(input: &Input, name: Option<&Ident>, en: &Enum)
 
     let reader_var = input.stream_ident_or(READER);
 
+    if let Some(magic_ty) = dispatch_magic_type(en) {
+        let read = generate_data_enum_dispatch(en, &reader_var, &magic_ty);
+        return quote! {
+            #prelude
+            #read
+        };
+    }
+
     let try_each_variant = en.variants.iter().map(|variant| {
         let body = generate_variant_impl(en, variant);
 
@@ -216,6 +225,86 @@ pub(super) fn generate_data_enum(input: &Input, name: Option<&Ident>, en: &Enum)
     }
 }
 
+// If every variant has a magic of the same type, the magic can be read once
+// and used to select the variant directly instead of trying each variant in
+// turn. Anything that would make the result depend on the order variants are
+// tried in falls back to the slow path.
+fn dispatch_magic_type(en: &Enum) -> Option<TokenStream> {
+    let mut kind = None;
+    let mut seen = HashSet::new();
+    for variant in &en.variants {
+        let (magic, simple) = match variant {
+            EnumVariant::Variant { options, .. } => (
+                options.magic.as_ref()?,
+                matches!(options.endian, CondEndian::Inherited)
+                    && options.map_stream.is_none()
+                    && options.pad_size_to.is_none(),
+            ),
+            EnumVariant::Unit(options) => (options.magic.as_ref()?, true),
+        };
+
+        if !simple || magic.binding().is_some() || *kind.get_or_insert(magic.kind()) != magic.kind()
+        {
+            return None;
+        }
+
+        for value in magic.canonical_values()? {
+            if !seen.insert(value) {
+                return None;
+            }
+        }
+    }
+
+    kind.map(TokenStream::from)
+}
+
+fn generate_data_enum_dispatch(
+    en: &Enum,
+    reader_var: &TokenStream,
+    magic_ty: &TokenStream,
+) -> TokenStream {
+    let amp = en.variants[0].magic().as_ref().map(|magic| magic.add_ref());
+
+    let matches = en.variants.iter().map(|variant| {
+        let magic = variant.magic().as_ref().map(|magic| magic.match_pattern());
+        let error = if en.error_mode == EnumErrorMode::ReturnUnexpectedError {
+            quote! {
+                #BIN_ERROR::NoVariantMatch { pos: #POS }
+            }
+        } else {
+            let name = variant.ident().to_string();
+            quote! {
+                #BIN_ERROR::EnumErrors {
+                    pos: #POS,
+                    variant_errors: alloc::vec![(#name, #TEMP)]
+                }
+            }
+        };
+
+        let mut variant = variant.clone();
+        *variant.magic_mut() = None;
+        let body = generate_variant_impl(en, &variant);
+
+        quote! {
+            #magic => (|| {
+                #body
+            })().map_err(|#TEMP: #BIN_ERROR| #error)
+        }
+    });
+
+    quote! {
+        (|| {
+            extern crate alloc;
+            let #TEMP: #magic_ty = #READ_METHOD(#reader_var, #OPT, ())?;
+            match #amp #TEMP {
+                #(#matches,)*
+                _ => Err(#BIN_ERROR::NoVariantMatch { pos: #POS })
+            }
+        })()
+        .or_else(#RESTORE_POSITION::<#BIN_ERROR, _, _>(#reader_var, #POS))
+    }
+}
+
 fn generate_variant_impl(en: &Enum, variant: &EnumVariant) -> TokenStream {
     let input = Input::Struct(variant.clone().into());
 
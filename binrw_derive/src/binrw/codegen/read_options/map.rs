@@ -1,10 +1,13 @@
 use super::PreludeGenerator;
-use crate::binrw::{
-    codegen::{
-        get_assertions, get_map_err,
-        sanitization::{ARGS, OPT, POS, READER, READ_METHOD, THIS},
+use crate::{
+    binrw::{
+        codegen::{
+            get_assertions, get_map_err,
+            sanitization::{ARGS, OPT, PARSE_FN_TYPE_HINT, POS, READER, READ_METHOD, THIS},
+        },
+        parser::Input,
     },
-    parser::Input,
+    util::quote_spanned_any,
 };
 use proc_macro2::TokenStream;
 use quote::quote;
@@ -80,6 +83,39 @@ pub(crate) fn generate_try_map(
     }
 }
 
+pub(crate) fn generate_parse_with(input: &Input, parse_with: &TokenStream) -> TokenStream {
+    let prelude = PreludeGenerator::new(input)
+        .add_endian()
+        .add_magic_pre_assertion()
+        .add_map_stream()
+        .finish();
+
+    let destructure_ref = destructure_ref(input);
+    let assertions = field_asserts(input).chain(get_assertions(input.assertions()));
+    let reader_var = input.stream_ident_or(READER);
+    let parse_fn = quote_spanned_any! { parse_with.span()=>
+        #PARSE_FN_TYPE_HINT::<Self, _, _, _>(#parse_with)
+    };
+
+    // Imports are not destructured since the arguments are passed through
+    // to the parser function as-is
+    quote! {
+        #prelude
+
+        (#parse_fn)(#reader_var, #OPT, #ARGS).and_then(|#THIS| {
+            #destructure_ref
+
+            (|| {
+                #(
+                    #assertions
+                )*
+
+                Ok(())
+            })().map(|_: ()| #THIS)
+        })
+    }
+}
+
 fn destructure_ref(input: &Input) -> Option<TokenStream> {
     match input {
         Input::Struct(input) => {
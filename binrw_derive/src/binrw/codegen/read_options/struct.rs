@@ -1,16 +1,17 @@
 use super::{get_magic, PreludeGenerator};
 #[cfg(feature = "verbose-backtrace")]
 use crate::binrw::backtrace::BacktraceFrame;
-use crate::binrw::parser::Assert;
+use crate::binrw::parser::{Assert, AssertionError};
 use crate::{
     binrw::{
         codegen::{
-            get_assertions, get_endian, get_map_err, get_passed_args, get_try_calc,
+            get_assertions, get_endian, get_map_err, get_passed_args, get_set_endian, get_try_calc,
             sanitization::{
-                make_ident, ARGS_TYPE_HINT, BACKTRACE_FRAME, BINREAD_TRAIT, COERCE_FN,
-                DBG_EPRINTLN, MAP_ARGS_TYPE_HINT, MAP_READER_TYPE_HINT, OPT, PARSE_FN_TYPE_HINT,
-                POS, READER, READ_FUNCTION, READ_METHOD, REQUIRED_ARG_TRAIT, SAVED_POSITION,
-                SEEK_FROM, SEEK_TRAIT, TEMP, THIS, WITH_CONTEXT,
+                make_ident, ARGS_TYPE_HINT, BACKTRACE_FRAME, BINREAD_TRAIT, BIN_ERROR, COERCE_FN,
+                DBG_EPRINTLN, MAP_ARGS_TYPE_HINT, MAP_READER_TYPE_HINT, NOT_EOF, OPT, PAD_SIZE_TO,
+                PARSE_FN_TYPE_HINT, POS, READER, READ_FUNCTION, READ_METHOD, RECOVER, RECOVERING,
+                REQUIRED_ARG_TRAIT, SAVED_POSITION, SEEK_FROM, SEEK_TRAIT, SIZE, TAKE_SEEK,
+                TAKE_SEEK_EXT, TEMP, THIS, WARN, WITH_CONTEXT,
             },
         },
         parser::{ErrContext, FieldMode, Input, Map, Struct, StructField},
@@ -27,7 +28,10 @@ pub(super) fn generate_unit_struct(
     name: Option<&Ident>,
     variant_ident: Option<&Ident>,
 ) -> TokenStream {
-    let prelude = get_prelude(input, name);
+    let prelude = match input {
+        Input::UnitStruct(st) => wrap_pad_size_to(input, st, get_prelude(input, name)),
+        _ => get_prelude(input, name),
+    };
     let return_type = get_return_type(variant_ident);
     quote! {
         #prelude
@@ -98,15 +102,27 @@ impl<'input> StructGenerator<'input> {
 
     pub(super) fn read_fields(mut self, name: Option<&Ident>, variant_name: Option<&str>) -> Self {
         let prelude = get_prelude(self.input, name);
+        let recover = self.st.recover.is_some();
         let read_fields = self
             .st
             .fields
             .iter()
-            .map(|field| generate_field(self.input, field, name, variant_name));
-        self.out = quote! {
-            #prelude
-            #(#read_fields)*
-        };
+            .map(|field| generate_field(self.input, field, name, variant_name, recover));
+        let recovering = recover.then(|| {
+            quote! {
+                #[allow(unused_mut, unused_variables, unused_assignments)]
+                let mut #RECOVERING = false;
+            }
+        });
+        self.out = wrap_pad_size_to(
+            self.input,
+            self.st,
+            quote! {
+                #prelude
+                #recovering
+                #(#read_fields)*
+            },
+        );
 
         self
     }
@@ -146,13 +162,14 @@ fn generate_field(
     field: &StructField,
     name: Option<&Ident>,
     variant_name: Option<&str>,
+    recover: bool,
 ) -> TokenStream {
     // temp + ignore == just don't bother
-    if field.is_temp(false) && matches!(field.field_mode, FieldMode::Default) {
-        return TokenStream::new();
+    if field.is_temp(false) && matches!(field.field_mode, FieldMode::Default(None)) {
+        return get_set_endian(field.set_endian.as_ref()).unwrap_or_default();
     }
 
-    FieldGenerator::new(input, field)
+    FieldGenerator::new(input, field, recover)
         .read_value()
         .wrap_map_stream()
         .try_conversion(name, variant_name)
@@ -162,16 +179,19 @@ fn generate_field(
         .wrap_condition()
         .assign_to_var()
         .append_assertions()
+        .append_warnings()
         .wrap_restore_position()
         .prefix_magic()
         .prefix_args_and_options()
         .prefix_map_function()
         .prefix_read_function()
+        .append_set_endian()
         .finish()
 }
 
 struct FieldGenerator<'field> {
     field: &'field StructField,
+    recover: bool,
     out: TokenStream,
     outer_reader_var: TokenStream,
     reader_var: TokenStream,
@@ -180,11 +200,12 @@ struct FieldGenerator<'field> {
 }
 
 impl<'field> FieldGenerator<'field> {
-    fn new(input: &Input, field: &'field StructField) -> Self {
+    fn new(input: &Input, field: &'field StructField, recover: bool) -> Self {
         let (reader_var, endian_var, args_var) = make_field_vars(input, field);
 
         Self {
             field,
+            recover,
             out: TokenStream::new(),
             outer_reader_var: input.stream_ident_or(READER),
             reader_var,
@@ -269,6 +290,44 @@ impl<'field> FieldGenerator<'field> {
         self
     }
 
+    fn append_warnings(mut self) -> Self {
+        let warnings = self.field.warnings.iter().filter_map(
+            |Assert {
+                 kw_span,
+                 condition,
+                 consequent,
+                 ..
+             }| {
+                let AssertionError::Message(message) = consequent else {
+                    return None;
+                };
+
+                Some(quote_spanned_any! {*kw_span=>
+                    #WARN(#condition, #POS, || { #message });
+                })
+            },
+        );
+        let head = self.out;
+        self.out = quote! {
+            #head
+            #(#warnings)*
+        };
+
+        self
+    }
+
+    fn append_set_endian(mut self) -> Self {
+        if let Some(set_endian) = get_set_endian(self.field.set_endian.as_ref()) {
+            let head = self.out;
+            self.out = quote! {
+                #head
+                #set_endian
+            };
+        }
+
+        self
+    }
+
     fn assign_to_var(mut self) -> Self {
         let ident = &self.field.ident;
         let ty = &self.field.ty;
@@ -344,12 +403,26 @@ impl<'field> FieldGenerator<'field> {
     }
 
     fn wrap_map_stream(mut self) -> Self {
+        let reader_var = &self.reader_var;
+        let outer_reader_var = &self.outer_reader_var;
+
         if let Some(map_stream) = &self.field.map_stream {
             let rest = self.out;
-            let reader_var = &self.reader_var;
-            let outer_reader_var = &self.outer_reader_var;
+            let (inner_reader_var, reader_ty) = if self.field.size.is_some() {
+                (reader_var, quote! { #TAKE_SEEK<&mut R> })
+            } else {
+                (outer_reader_var, quote! { R })
+            };
             self.out = quote_spanned_any! { map_stream.span()=> {
-                let #reader_var = &mut #MAP_READER_TYPE_HINT::<R, _, _>(#map_stream)(#outer_reader_var);
+                let #reader_var = &mut #MAP_READER_TYPE_HINT::<#reader_ty, _, _>(#map_stream)(#inner_reader_var);
+                #rest
+            }};
+        }
+
+        if self.field.size.is_some() {
+            let rest = self.out;
+            self.out = quote! {{
+                let #reader_var = &mut #TAKE_SEEK_EXT::take_seek(&mut *#outer_reader_var, #SIZE);
                 #rest
             }};
         }
@@ -382,12 +455,12 @@ impl<'field> FieldGenerator<'field> {
     fn prefix_args_and_options(mut self) -> Self {
         let args = self.args_var.as_ref().map(|args_var| {
             let map_func = make_ident(&self.field.ident, "map_func");
-            let args = get_passed_args(self.field, READER);
+            let args = get_passed_args(self.field, READER, false);
             let ty = &self.field.ty;
 
             if let FieldMode::Function(_) = &self.field.field_mode {
                 quote_spanned! {ty.span()=>
-                    let #args_var = #ARGS_TYPE_HINT::<R, #ty, _, _>(#READ_FUNCTION, #args);
+                    let #args_var = #ARGS_TYPE_HINT::<R, #ty, _, _>(&#READ_FUNCTION, #args);
                 }
             } else {
                 match &self.field.map {
@@ -437,7 +510,8 @@ impl<'field> FieldGenerator<'field> {
 
     fn read_value(mut self) -> Self {
         self.out = match &self.field.field_mode {
-            FieldMode::Default => quote! { <_>::default() },
+            FieldMode::Default(None) => quote! { <_>::default() },
+            FieldMode::Default(Some(default)) => quote! { #default },
             FieldMode::Calc(calc) => quote! { #calc },
             FieldMode::TryCalc(calc) => get_try_calc(POS, &self.field.ty, calc),
             read_mode @ (FieldMode::Normal | FieldMode::Function(_)) => {
@@ -473,10 +547,26 @@ impl<'field> FieldGenerator<'field> {
     fn try_conversion(mut self, name: Option<&Ident>, variant_name: Option<&str>) -> Self {
         if !self.field.generated_value() {
             let result = self.out;
+            let reader_var = &self.outer_reader_var;
             self.out = if self.field.do_try.is_some() {
                 quote! { #result.unwrap_or_default() }
+            } else if self.recover {
+                let context = field_context(self.field, name, variant_name);
+                quote! {
+                    if #RECOVERING {
+                        <_>::default()
+                    } else {
+                        match #result {
+                            Ok(value) => value,
+                            Err(error) => {
+                                #RECOVERING = true;
+                                #RECOVER(&error, #reader_var, #context)
+                            }
+                        }
+                    }
+                }
             } else {
-                let map_err = get_err_context(self.field, name, variant_name);
+                let map_err = get_err_context(reader_var, self.field, name, variant_name);
                 quote! { #result #map_err ? }
             };
         }
@@ -485,12 +575,26 @@ impl<'field> FieldGenerator<'field> {
     }
 
     fn wrap_condition(mut self) -> Self {
-        if let Some(cond) = &self.field.if_cond {
-            let condition = &cond.condition;
+        let not_eof = self.field.if_not_eof.map(|()| {
+            let reader_var = &self.outer_reader_var;
+            quote! { #NOT_EOF(#reader_var)? }
+        });
+        let condition = match (&self.field.if_cond, not_eof) {
+            (Some(cond), Some(not_eof)) => {
+                let condition = &cond.condition;
+                Some(quote! { (#condition) && #not_eof })
+            }
+            (Some(cond), None) => Some(cond.condition.clone()),
+            (None, not_eof) => not_eof,
+        };
+
+        if let Some(condition) = condition {
             let consequent = self.out;
-            let alternate = cond
-                .alternate
+            let alternate = self
+                .field
+                .if_cond
                 .as_ref()
+                .and_then(|cond| cond.alternate.as_ref())
                 .map_or_else(|| Cow::Owned(quote! { <_>::default() }), Cow::Borrowed);
             self.out = quote! {
                 if #condition {
@@ -530,6 +634,7 @@ impl<'field> FieldGenerator<'field> {
 }
 
 fn get_err_context(
+    reader_var: &TokenStream,
     field: &StructField,
     name: Option<&Ident>,
     variant_name: Option<&str>,
@@ -563,12 +668,16 @@ fn get_err_context(
                 }
             }
         } else {
-            format!(
-                "While parsing field '{}' in {}",
-                field.ident,
-                name.map_or_else(|| variant_name.unwrap().into(), ToString::to_string)
-            )
-            .into_token_stream()
+            let message = field_context(field, name, variant_name);
+            quote! {
+                {
+                    extern crate alloc;
+                    match #SEEK_TRAIT::stream_position(#reader_var).ok().or_else(|| err.pos()) {
+                        Some(pos) => alloc::borrow::Cow::Owned(alloc::format!("{} at {:#x}", #message, pos)),
+                        None => alloc::borrow::Cow::Borrowed(#message),
+                    }
+                }
+            }
         };
 
         quote_spanned! {field.ident.span()=>
@@ -582,10 +691,22 @@ fn get_err_context(
     };
 
     quote! {
-        .map_err(|err| #WITH_CONTEXT(err, #backtrace))
+        .map_err(|err| {
+            let #TEMP = #backtrace;
+            #WITH_CONTEXT(err, #TEMP)
+        })
     }
 }
 
+/// Returns a description of the field for use in error messages.
+fn field_context(field: &StructField, name: Option<&Ident>, variant_name: Option<&str>) -> String {
+    format!(
+        "While parsing field '{}' in {}",
+        field.ident,
+        name.map_or_else(|| variant_name.unwrap().into(), ToString::to_string)
+    )
+}
+
 fn get_prelude(input: &Input, name: Option<&Ident>) -> TokenStream {
     PreludeGenerator::new(input)
         .add_imports(name)
@@ -596,14 +717,15 @@ fn get_prelude(input: &Input, name: Option<&Ident>) -> TokenStream {
 }
 
 fn generate_seek_after(reader_var: &TokenStream, field: &StructField) -> TokenStream {
+    let size = field.size.as_ref().map(|_| {
+        quote! {
+            #SEEK_TRAIT::seek(#reader_var, #SEEK_FROM::Start(#POS + #SIZE))?;
+        }
+    });
     let pad_size_to = field.pad_size_to.as_ref().map(|pad| {
-        quote! {{
-            let pad = (#pad) as i64;
-            let size = (#SEEK_TRAIT::stream_position(#reader_var)? - #POS) as i64;
-            if size < pad {
-                #SEEK_TRAIT::seek(#reader_var, #SEEK_FROM::Current(pad - size))?;
-            }
-        }}
+        quote! {
+            #PAD_SIZE_TO(#reader_var, #POS, #pad)?;
+        }
     });
     let pad_after = field
         .pad_after
@@ -615,6 +737,7 @@ fn generate_seek_after(reader_var: &TokenStream, field: &StructField) -> TokenSt
         .map(|value| map_align(reader_var, value));
 
     quote! {
+        #size
         #pad_size_to
         #pad_after
         #align_after
@@ -635,17 +758,48 @@ fn generate_seek_before(reader_var: &TokenStream, field: &StructField) -> TokenS
         .align_before
         .as_ref()
         .map(|value| map_align(reader_var, value));
-    let pad_size_to_before = field.pad_size_to.as_ref().map(|_| {
+    let pos_before = (field.pad_size_to.is_some() || field.size.is_some()).then(|| {
         quote! {
             let #POS = #SEEK_TRAIT::stream_position(#reader_var)?;
         }
     });
+    let size_before = field.size.as_ref().map(|size| {
+        quote_spanned_any! {size.span()=>
+            let #SIZE = {
+                let #TEMP = #size;
+                #[allow(clippy::useless_conversion, clippy::unnecessary_fallible_conversions)]
+                u64::try_from(#TEMP)
+                    .ok()
+                    .filter(|size| #POS.checked_add(*size).is_some())
+                    .ok_or_else(|| {
+                        extern crate alloc;
+                        #BIN_ERROR::AssertFail {
+                            pos: #POS,
+                            message: alloc::format!("size {:?} out of range", #TEMP)
+                        }
+                    })?
+            };
+        }
+    });
 
     quote! {
         #seek_before
         #pad_before
         #align_before
-        #pad_size_to_before
+        #pos_before
+        #size_before
+    }
+}
+
+fn wrap_pad_size_to(input: &Input, st: &Struct, body: TokenStream) -> TokenStream {
+    if let Some(pad) = &st.pad_size_to {
+        let reader_var = input.stream_ident_or(READER);
+        quote! {
+            #body
+            #PAD_SIZE_TO(#reader_var, #POS, #pad)?;
+        }
+    } else {
+        body
     }
 }
 
@@ -657,7 +811,7 @@ fn make_field_vars(
     input: &Input,
     field: &StructField,
 ) -> (TokenStream, TokenStream, Option<Ident>) {
-    let reader_var = if field.map_stream.is_some() {
+    let reader_var = if field.map_stream.is_some() || field.size.is_some() {
         make_ident(&field.ident, "reader").into_token_stream()
     } else {
         input.stream_ident_or(READER)
@@ -30,6 +30,8 @@ ident_str! {
     pub(crate) WRITE_TRAIT = from_crate!(io::Write);
     pub(crate) SEEK_TRAIT = from_crate!(io::Seek);
     pub(crate) SEEK_FROM = from_crate!(io::SeekFrom);
+    pub(crate) TAKE_SEEK = from_crate!(io::TakeSeek);
+    pub(crate) TAKE_SEEK_EXT = from_crate!(io::TakeSeekExt);
     pub(crate) BIN_RESULT = from_crate!(BinResult);
     pub(crate) ENDIAN_ENUM = from_crate!(Endian);
     pub(crate) READ_METHOD = from_read_trait!(read_options);
@@ -40,9 +42,18 @@ ident_str! {
     pub(crate) ARGS = "__binrw_generated_var_arguments";
     pub(crate) SAVED_POSITION = "__binrw_generated_saved_position";
     pub(crate) ASSERT_MAGIC = from_crate!(__private::magic);
+    pub(crate) ASSERT_MAGIC_ONE_OF = from_crate!(__private::magic_one_of);
+    pub(crate) WRITE_MAGIC_ONE_OF = from_crate!(__private::write_magic_one_of);
+    pub(crate) PAD_SIZE_TO = from_crate!(__private::pad_size_to);
     pub(crate) ASSERT = from_crate!(__private::assert);
+    pub(crate) WARN = from_crate!(__private::warn);
+    pub(crate) RECOVER = from_crate!(__private::recover);
     pub(crate) ASSERT_ERROR_FN = from_crate!(__private::AssertErrorFn);
+    pub(crate) CUSTOM_ERR = from_crate!(__private::CustomErr);
+    pub(crate) STD_CUSTOM_ERR_TRAIT = from_crate!(__private::StdCustomErr);
+    pub(crate) ANY_CUSTOM_ERR_TRAIT = from_crate!(__private::AnyCustomErr);
     pub(crate) COERCE_FN = from_crate!(__private::coerce_fn);
+    pub(crate) NOT_EOF = from_crate!(__private::not_eof);
     pub(crate) ARGS_TYPE_HINT = from_crate!(__private::parse_function_args_type_hint);
     pub(crate) MAP_ARGS_TYPE_HINT = from_crate!(__private::map_args_type_hint);
     pub(crate) REQUIRED_ARG_TRAIT = from_crate!(__private::Required);
@@ -59,7 +70,12 @@ ident_str! {
     pub(crate) RESTORE_POSITION = from_crate!(__private::restore_position);
     pub(crate) RESTORE_POSITION_VARIANT = from_crate!(__private::restore_position_variant);
     pub(crate) WRITE_ZEROES = from_crate!(__private::write_zeroes);
+    pub(crate) WRITE_FILL = from_crate!(__private::write_fill);
+    pub(crate) WRITE_COUNT = from_crate!(__private::write_count);
     pub(crate) ARGS_MACRO = from_crate!(args);
+    pub(crate) READ_TO_END = from_crate!(helpers::read_to_end);
+    pub(crate) STRIDE = from_crate!(helpers::stride);
+    pub(crate) WRITE_STRIDE = from_crate!(helpers::write_stride);
     pub(crate) META_ENDIAN_KIND = from_crate!(meta::EndianKind);
     pub(crate) READ_ENDIAN = from_crate!(meta::ReadEndian);
     pub(crate) READ_MAGIC = from_crate!(meta::ReadMagic);
@@ -70,10 +86,13 @@ ident_str! {
     pub(crate) TEMP = "__binrw_temp";
     pub(crate) THIS = "__binrw_this";
     pub(crate) POS = "__binrw_generated_position_temp";
+    pub(crate) SIZE = "__binrw_generated_size_temp";
+    pub(crate) RECOVERING = "__binrw_generated_recovering";
     pub(crate) ERROR_BASKET = "__binrw_generated_error_basket";
     pub(crate) READ_FUNCTION = "__binrw_generated_read_function";
     pub(crate) WRITE_FUNCTION = "__binrw_generated_write_function";
     pub(crate) BEFORE_POS = "__binrw_generated_before_pos";
+    pub(crate) STRUCT_POS = "__binrw_generated_struct_position";
     pub(crate) DBG_EPRINTLN = from_crate!(__private::eprintln);
 }
 
@@ -3,10 +3,12 @@ mod prelude;
 mod r#struct;
 mod struct_field;
 
-use super::get_map_err;
+use super::{get_map_err, uses_offset_from_start};
 use crate::binrw::{
-    codegen::sanitization::{OPT, POS, SEEK_TRAIT, WRITER, WRITE_METHOD},
-    parser::{Input, Map},
+    codegen::sanitization::{
+        OPT, POS, SEEK_TRAIT, STRUCT_POS, WRITER, WRITE_MAGIC_ONE_OF, WRITE_METHOD,
+    },
+    parser::{Input, Magic, Map},
 };
 use proc_macro2::TokenStream;
 use quote::quote;
@@ -31,9 +33,16 @@ pub(crate) fn generate(input: &Input, derive_input: &syn::DeriveInput) -> TokenS
 
     let writer_var = input.stream_ident_or(WRITER);
 
+    let struct_pos = uses_offset_from_start(input).then(|| {
+        quote! {
+            let #STRUCT_POS = #POS;
+        }
+    });
+
     quote! {
         let #writer_var = #WRITER;
         let #POS = #SEEK_TRAIT::stream_position(#writer_var)?;
+        #struct_pos
         #inner
 
         Ok(())
@@ -68,3 +77,28 @@ fn generate_map(input: &Input, name: Option<&Ident>, map: &TokenStream) -> Token
         .prefix_imports()
         .finish()
 }
+
+/// Generates code to write a magic value. If the magic is bound to a field,
+/// the field value is checked against the allowed alternatives first.
+fn write_magic(writer_var: &TokenStream, endian: &TokenStream, magic: &Magic) -> TokenStream {
+    let Some(magic) = magic else {
+        return TokenStream::new();
+    };
+
+    if let Some(binding) = magic.binding() {
+        let values = magic.deref_values();
+        quote! {
+            #WRITE_MAGIC_ONE_OF(#writer_var, &[#(#values),*], &#binding, #endian)?;
+        }
+    } else {
+        let magic = magic.write_value();
+        quote! {
+            #WRITE_METHOD (
+                &#magic,
+                #writer_var,
+                #endian,
+                ()
+            )?;
+        }
+    }
+}
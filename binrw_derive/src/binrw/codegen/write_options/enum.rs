@@ -1,10 +1,10 @@
-use super::{prelude::PreludeGenerator, r#struct::StructGenerator};
+use super::{prelude::PreludeGenerator, r#struct::StructGenerator, write_magic};
 use crate::binrw::{
     codegen::sanitization::{OPT, WRITER, WRITE_METHOD},
     parser::{Enum, EnumVariant, Input, UnitEnumField, UnitOnlyEnum},
 };
 use proc_macro2::{Ident, TokenStream};
-use quote::quote;
+use quote::{quote, ToTokens};
 
 pub(crate) fn generate_unit_enum(
     input: &Input,
@@ -70,23 +70,13 @@ impl<'a> EnumGenerator<'a> {
                     StructGenerator::new(None, options, None, &self.writer_var)
                         .write_fields()
                         .prefix_prelude()
+                        .wrap_pad_size_to()
+                        .wrap_deferred()
                         .finish()
                 }
-                EnumVariant::Unit(variant) => variant
-                    .magic
-                    .as_ref()
-                    .map(|magic| {
-                        let magic = magic.match_value();
-                        quote! {
-                            #WRITE_METHOD (
-                                &#magic,
-                                #writer_var,
-                                #OPT,
-                                ()
-                            )?;
-                        }
-                    })
-                    .unwrap_or_default(),
+                EnumVariant::Unit(variant) => {
+                    write_magic(writer_var, &OPT.to_token_stream(), &variant.magic)
+                }
             };
 
             quote! {
@@ -150,18 +140,7 @@ fn generate_unit_enum_repr(
 fn generate_unit_enum_magic(writer_var: &TokenStream, variants: &[UnitEnumField]) -> TokenStream {
     let branches = variants.iter().map(|variant| {
         let name = &variant.ident;
-        let magic = variant.magic.as_ref().map(|magic| {
-            let magic = magic.match_value();
-
-            quote! {
-                #WRITE_METHOD (
-                    &#magic,
-                    #writer_var,
-                    #OPT,
-                    (),
-                )?;
-            }
-        });
+        let magic = write_magic(writer_var, &OPT.to_token_stream(), &variant.magic);
 
         quote! {
             Self::#name => {
@@ -1,15 +1,16 @@
+use super::write_magic;
 use crate::{
     binrw::{
         codegen::{
             get_destructured_imports, get_endian,
-            sanitization::{ARGS, MAP_WRITER_TYPE_HINT, OPT, WRITER, WRITE_METHOD},
+            sanitization::{ARGS, MAP_WRITER_TYPE_HINT, OPT, WRITER},
         },
         parser::{CondEndian, Input, Magic},
     },
     util::quote_spanned_any,
 };
 use proc_macro2::{Ident, TokenStream};
-use quote::quote;
+use quote::{quote, ToTokens};
 use syn::spanned::Spanned;
 
 pub(crate) struct PreludeGenerator<'a> {
@@ -50,17 +51,11 @@ impl<'a> PreludeGenerator<'a> {
     }
 
     pub(crate) fn prefix_magic(mut self, magic: &Magic) -> Self {
-        if let Some(magic) = magic {
-            let writer_var = &self.writer_var;
-            let magic = magic.match_value();
+        if magic.is_some() {
+            let magic = write_magic(self.writer_var, &OPT.to_token_stream(), magic);
             let out = self.out;
             self.out = quote! {
-                #WRITE_METHOD (
-                    &#magic,
-                    #writer_var,
-                    #OPT,
-                    ()
-                )?;
+                #magic
 
                 #out
             };
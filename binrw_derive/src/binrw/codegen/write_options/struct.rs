@@ -1,6 +1,12 @@
-use super::{prelude::PreludeGenerator, struct_field::write_field};
+use super::{
+    prelude::PreludeGenerator,
+    struct_field::{deferred_ident, write_field, write_padding},
+};
 use crate::binrw::{
-    codegen::{get_assertions, sanitization::WRITER},
+    codegen::{
+        get_assertions,
+        sanitization::{SEEK_TRAIT, STRUCT_POS, WRITER},
+    },
     parser::{Input, Struct},
 };
 use proc_macro2::TokenStream;
@@ -12,6 +18,8 @@ pub(super) fn generate_struct(input: &Input, name: Option<&Ident>, st: &Struct)
         .write_fields()
         .prefix_assertions()
         .prefix_prelude()
+        .wrap_pad_size_to()
+        .wrap_deferred()
         .prefix_borrow_fields()
         .finish()
 }
@@ -63,12 +71,64 @@ impl<'input> StructGenerator<'input> {
         self
     }
 
+    pub(crate) fn wrap_pad_size_to(mut self) -> Self {
+        if let Some(size) = &self.st.pad_size_to {
+            let writer_var = self.writer_var;
+            let write_padding =
+                write_padding(writer_var, self.st.fill.as_ref(), &quote! { padding });
+            let out = self.out;
+            self.out = quote! {
+                let #STRUCT_POS = #SEEK_TRAIT::stream_position(#writer_var)?;
+                #out
+                {
+                    let pad_to_size = (#size) as u64;
+                    let after_pos = #SEEK_TRAIT::stream_position(#writer_var)?;
+                    if let Some(size) = after_pos.checked_sub(#STRUCT_POS) {
+                        if let Some(padding) = pad_to_size.checked_sub(size) {
+                            #write_padding
+                        }
+                    }
+                }
+            };
+        }
+
+        self
+    }
+
+    /// Writes the pointed-to values of `deferred` fields after the rest of the
+    /// object.
+    pub(crate) fn wrap_deferred(mut self) -> Self {
+        let deferred = self
+            .st
+            .fields
+            .iter()
+            .filter(|field| field.deferred.is_some())
+            .map(|field| deferred_ident(&field.ident))
+            .collect::<Vec<_>>();
+
+        if !deferred.is_empty() {
+            let writer_var = self.writer_var;
+            let out = self.out;
+            self.out = quote! {
+                #(let mut #deferred = None;)*
+                #out
+                #(
+                    if let Some(deferred) = #deferred {
+                        deferred.finish(#writer_var)?;
+                    }
+                )*
+            };
+        }
+
+        self
+    }
+
     pub(crate) fn write_fields(mut self) -> Self {
         let write_fields = self
             .st
             .fields
             .iter()
-            .map(|field| write_field(self.writer_var, field));
+            .map(|field| write_field(self.writer_var, field, self.st.fill.as_ref()));
 
         self.out = quote! {
             #(#write_fields)*
@@ -1,11 +1,12 @@
+use super::write_magic;
 use crate::{
     binrw::{
         codegen::{
-            get_assertions, get_endian, get_map_err, get_passed_args, get_try_calc,
+            get_assertions, get_endian, get_map_err, get_passed_args, get_set_endian, get_try_calc,
             sanitization::{
                 make_ident, BEFORE_POS, BINWRITE_TRAIT, MAP_WRITER_TYPE_HINT, POS,
-                REQUIRED_ARG_TRAIT, SAVED_POSITION, SEEK_FROM, SEEK_TRAIT, WRITER,
-                WRITE_ARGS_TYPE_HINT, WRITE_FN_MAP_OUTPUT_TYPE_HINT,
+                REQUIRED_ARG_TRAIT, SAVED_POSITION, SEEK_FROM, SEEK_TRAIT, TEMP, WRITER,
+                WRITE_ARGS_TYPE_HINT, WRITE_COUNT, WRITE_FILL, WRITE_FN_MAP_OUTPUT_TYPE_HINT,
                 WRITE_FN_TRY_MAP_OUTPUT_TYPE_HINT, WRITE_FN_TYPE_HINT, WRITE_FUNCTION,
                 WRITE_MAP_ARGS_TYPE_HINT, WRITE_MAP_INPUT_TYPE_HINT, WRITE_METHOD,
                 WRITE_TRY_MAP_ARGS_TYPE_HINT, WRITE_ZEROES,
@@ -21,8 +22,12 @@ use proc_macro2::TokenStream;
 use quote::{quote, quote_spanned, ToTokens};
 use syn::{spanned::Spanned, Ident};
 
-pub(crate) fn write_field(writer_var: &TokenStream, field: &StructField) -> TokenStream {
-    StructFieldGenerator::new(field, writer_var)
+pub(crate) fn write_field(
+    writer_var: &TokenStream,
+    field: &StructField,
+    fill: Option<&TokenStream>,
+) -> TokenStream {
+    StructFieldGenerator::new(field, writer_var, fill)
         .write_field()
         .wrap_map_stream()
         .prefix_map_value()
@@ -34,6 +39,7 @@ pub(crate) fn write_field(writer_var: &TokenStream, field: &StructField) -> Toke
         .prefix_args()
         .prefix_write_function()
         .prefix_map_function()
+        .append_set_endian()
         .finish()
 }
 
@@ -41,11 +47,16 @@ struct StructFieldGenerator<'input> {
     field: &'input StructField,
     outer_writer_var: &'input TokenStream,
     writer_var: Cow<'input, TokenStream>,
+    fill: Option<&'input TokenStream>,
     out: TokenStream,
 }
 
 impl<'a> StructFieldGenerator<'a> {
-    fn new(field: &'a StructField, outer_writer_var: &'a TokenStream) -> Self {
+    fn new(
+        field: &'a StructField,
+        outer_writer_var: &'a TokenStream,
+        fill: Option<&'a TokenStream>,
+    ) -> Self {
         Self {
             field,
             outer_writer_var,
@@ -54,6 +65,7 @@ impl<'a> StructFieldGenerator<'a> {
             } else {
                 Cow::Borrowed(outer_writer_var)
             },
+            fill: field.fill.as_ref().or(fill),
             out: TokenStream::new(),
         }
     }
@@ -70,6 +82,18 @@ impl<'a> StructFieldGenerator<'a> {
         self
     }
 
+    fn append_set_endian(mut self) -> Self {
+        if let Some(set_endian) = get_set_endian(self.field.set_endian.as_ref()) {
+            let head = self.out;
+            self.out = quote! {
+                #head
+                #set_endian
+            };
+        }
+
+        self
+    }
+
     fn wrap_map_stream(mut self) -> Self {
         if let Some(map_stream) = &self.field.map_stream {
             let rest = self.out;
@@ -85,7 +109,7 @@ impl<'a> StructFieldGenerator<'a> {
     }
 
     fn prefix_write_function(mut self) -> Self {
-        if !self.field.is_written() {
+        if !self.field.is_written() || self.field.deferred.is_some() {
             return self;
         }
 
@@ -94,7 +118,7 @@ impl<'a> StructFieldGenerator<'a> {
                 quote! { #WRITE_METHOD }
             }
             FieldMode::Function(write_fn) => write_fn.clone(),
-            FieldMode::Default => unreachable!("Ignored fields are not written"),
+            FieldMode::Default(_) => unreachable!("Ignored fields are not written"),
         };
 
         let write_fn = if self.field.map.is_some() {
@@ -213,13 +237,34 @@ impl<'a> StructFieldGenerator<'a> {
             })
             .unwrap_or_else(|| quote::ToTokens::to_token_stream(name));
 
+        let check_count = self.field.count.as_ref().map(|count| {
+            quote_spanned_any! {count.span()=> {
+                // `clone` dereferences the count when it is a borrowed field
+                #[allow(clippy::clone_on_copy)]
+                let #TEMP = (#count).clone();
+                #WRITE_COUNT(#writer_var, &#name, #TEMP)?;
+            }}
+        });
+
+        let write = if self.field.deferred.is_some() {
+            let deferred = deferred_ident(&self.field.ident);
+            quote! {
+                #deferred = Some(#name.write_deferred(#writer_var, #endian, #args)?);
+            }
+        } else {
+            quote! {
+                #WRITE_FUNCTION(
+                    &#name,
+                    #writer_var,
+                    #endian,
+                    #args
+                )?;
+            }
+        };
+
         self.out = quote! {
-            #WRITE_FUNCTION(
-                &#name,
-                #writer_var,
-                #endian,
-                #args
-            )?;
+            #check_count
+            #write
         };
 
         self
@@ -244,8 +289,8 @@ impl<'a> StructFieldGenerator<'a> {
     fn wrap_padding(mut self) -> Self {
         let out = self.out;
 
-        let pad_before = pad_before(self.outer_writer_var, self.field);
-        let pad_after = pad_after(self.outer_writer_var, self.field);
+        let pad_before = pad_before(self.outer_writer_var, self.field, self.fill);
+        let pad_after = pad_after(self.outer_writer_var, self.field, self.fill);
         self.out = quote! {
             #pad_before
             #out
@@ -262,7 +307,7 @@ impl<'a> StructFieldGenerator<'a> {
 
         let args = args_ident(&self.field.ident);
 
-        let args_val = if let Some(args) = get_passed_args(self.field, WRITER) {
+        let args_val = if let Some(args) = get_passed_args(self.field, WRITER, true) {
             args
         } else {
             quote_spanned! { self.field.ty.span() => <_ as #REQUIRED_ARG_TRAIT>::args() }
@@ -296,30 +341,24 @@ impl<'a> StructFieldGenerator<'a> {
                 let ty = &self.field.ty;
                 quote! {
                     let #args = #WRITE_ARGS_TYPE_HINT::<#ty, W, _, _>(
-                        #WRITE_FUNCTION, #args_val
+                        &#WRITE_FUNCTION, #args_val
                     );
                     #out
                 }
             }
-            FieldMode::Default => unreachable!("Ignored fields are not written"),
+            FieldMode::Default(_) => unreachable!("Ignored fields are not written"),
         };
 
         self
     }
 
     fn prefix_magic(mut self) -> Self {
-        if let Some(magic) = &self.field.magic {
-            let magic = magic.match_value();
+        if self.field.magic.is_some() {
             let endian = get_endian(&self.field.endian);
-            let writer_var = &self.outer_writer_var;
+            let magic = write_magic(self.outer_writer_var, &endian, &self.field.magic);
             let out = self.out;
             self.out = quote! {
-                #WRITE_METHOD (
-                    &#magic,
-                    #writer_var,
-                    #endian,
-                    ()
-                )?;
+                #magic
 
                 #out
             };
@@ -337,6 +376,10 @@ fn args_ident(ident: &Ident) -> Ident {
     make_ident(ident, "args")
 }
 
+pub(super) fn deferred_ident(ident: &Ident) -> Ident {
+    make_ident(ident, "deferred")
+}
+
 fn field_mapping(map: &Map) -> Option<TokenStream> {
     match map {
         Map::Try(map_fn) | Map::Map(map_fn) => Some(quote! { (#map_fn) }),
@@ -349,30 +392,35 @@ fn map_func_ident(ident: &Ident) -> Ident {
     make_ident(ident, "map_func")
 }
 
-fn pad_after(writer_var: &TokenStream, field: &StructField) -> TokenStream {
+fn pad_after(
+    writer_var: &TokenStream,
+    field: &StructField,
+    fill: Option<&TokenStream>,
+) -> TokenStream {
     let pad_size_to = field.pad_size_to.as_ref().map(|size| {
+        let write_padding = write_padding(writer_var, fill, &quote! { padding });
         quote! {{
             let pad_to_size = (#size) as u64;
             let after_pos = #SEEK_TRAIT::stream_position(#writer_var)?;
             if let Some(size) = after_pos.checked_sub(#BEFORE_POS) {
                 if let Some(padding) = pad_to_size.checked_sub(size) {
-                    #WRITE_ZEROES(#writer_var, padding)?;
+                    #write_padding
                 }
             }
         }}
     });
-    let pad_after = field.pad_after.as_ref().map(|padding| {
-        quote! {
-            #WRITE_ZEROES(#writer_var, (#padding) as u64)?;
-        }
-    });
+    let pad_after = field
+        .pad_after
+        .as_ref()
+        .map(|padding| write_padding(writer_var, fill, &quote! { (#padding) as u64 }));
     let align_after = field.align_after.as_ref().map(|alignment| {
+        let write_padding = write_padding(writer_var, fill, &quote! { align - rem });
         quote! {{
             let pos = #SEEK_TRAIT::stream_position(#writer_var)?;
             let align = ((#alignment) as u64);
             let rem = pos % align;
             if rem != 0 {
-                #WRITE_ZEROES(#writer_var, align - rem)?;
+                #write_padding
             }
         }}
     });
@@ -390,7 +438,27 @@ fn pad_after(writer_var: &TokenStream, field: &StructField) -> TokenStream {
     }
 }
 
-fn pad_before(writer_var: &TokenStream, field: &StructField) -> TokenStream {
+pub(super) fn write_padding(
+    writer_var: &TokenStream,
+    fill: Option<&TokenStream>,
+    count: &TokenStream,
+) -> TokenStream {
+    if let Some(fill) = fill {
+        quote! {
+            #WRITE_FILL(#writer_var, #fill, #count)?;
+        }
+    } else {
+        quote! {
+            #WRITE_ZEROES(#writer_var, #count)?;
+        }
+    }
+}
+
+fn pad_before(
+    writer_var: &TokenStream,
+    field: &StructField,
+    fill: Option<&TokenStream>,
+) -> TokenStream {
     let seek_before = field.seek_before.as_ref().map(|seek| {
         quote! {
             #SEEK_TRAIT::seek(
@@ -399,18 +467,18 @@ fn pad_before(writer_var: &TokenStream, field: &StructField) -> TokenStream {
             )?;
         }
     });
-    let pad_before = field.pad_before.as_ref().map(|padding| {
-        quote! {
-            #WRITE_ZEROES(#writer_var, (#padding) as u64)?;
-        }
-    });
+    let pad_before = field
+        .pad_before
+        .as_ref()
+        .map(|padding| write_padding(writer_var, fill, &quote! { (#padding) as u64 }));
     let align_before = field.align_before.as_ref().map(|alignment| {
+        let write_padding = write_padding(writer_var, fill, &quote! { align - rem });
         quote! {{
             let pos = #SEEK_TRAIT::stream_position(#writer_var)?;
             let align = ((#alignment) as u64);
             let rem = pos % align;
             if rem != 0 {
-                #WRITE_ZEROES(#writer_var, align - rem)?;
+                #write_padding
             }
         }}
     });
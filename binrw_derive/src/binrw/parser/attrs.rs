@@ -1,7 +1,7 @@
 use super::keywords as kw;
 use crate::meta_types::{
-    IdentPatType, IdentTypeMaybeDefault, MetaEnclosedList, MetaExpr, MetaIdent, MetaList, MetaLit,
-    MetaType, MetaValue, MetaVoid,
+    IdentPatType, IdentTypeMaybeDefault, MetaEnclosedList, MetaExpr, MetaIdent, MetaList,
+    MetaLitAlternatives, MetaMaybeExpr, MetaType, MetaValue, MetaVoid,
 };
 use syn::{Expr, FieldValue, Token};
 
@@ -15,29 +15,40 @@ pub(super) type Big = MetaVoid<kw::big>;
 pub(super) type Calc = MetaExpr<kw::calc>;
 pub(super) type Count = MetaExpr<kw::count>;
 pub(super) type Debug = MetaVoid<kw::dbg>;
-pub(super) type Default = MetaVoid<kw::default>;
+pub(super) type Default = MetaMaybeExpr<kw::default>;
+pub(super) type Deferred = MetaVoid<kw::deferred>;
 pub(super) type ErrContext = MetaList<kw::err_context, Expr>;
+pub(super) type Fill = MetaExpr<kw::fill>;
 pub(super) type If = MetaList<Token![if], Expr>;
+pub(super) type IfNotEof = MetaVoid<kw::if_not_eof>;
 pub(super) type Ignore = MetaVoid<kw::ignore>;
 pub(super) type Import = MetaEnclosedList<kw::import, IdentPatType, IdentTypeMaybeDefault>;
 pub(super) type ImportRaw = MetaValue<kw::import_raw, IdentPatType>;
 pub(super) type IsBig = MetaExpr<kw::is_big>;
 pub(super) type IsLittle = MetaExpr<kw::is_little>;
 pub(super) type Little = MetaVoid<kw::little>;
-pub(super) type Magic = MetaLit<kw::magic>;
+pub(super) type Magic = MetaLitAlternatives<kw::magic>;
 pub(super) type Map = MetaExpr<kw::map>;
 pub(super) type MapStream = MetaExpr<kw::map_stream>;
 pub(super) type Offset = MetaExpr<kw::offset>;
+pub(super) type OffsetFromStart = MetaVoid<kw::offset_from_start>;
+pub(super) type OffsetMap = MetaExpr<kw::offset_map>;
 pub(super) type PadAfter = MetaExpr<kw::pad_after>;
 pub(super) type PadBefore = MetaExpr<kw::pad_before>;
 pub(super) type PadSizeTo = MetaExpr<kw::pad_size_to>;
 pub(super) type ParseWith = MetaExpr<kw::parse_with>;
 pub(super) type PreAssert = AssertLike<kw::pre_assert>;
+pub(super) type Warn = AssertLike<kw::warn>;
+pub(super) type Recover = MetaVoid<kw::recover>;
 pub(super) type Repr = MetaType<kw::repr>;
+pub(super) type Rest = MetaVoid<kw::rest>;
 pub(super) type RestorePosition = MetaVoid<kw::restore_position>;
 pub(super) type ReturnAllErrors = MetaVoid<kw::return_all_errors>;
 pub(super) type ReturnUnexpectedError = MetaVoid<kw::return_unexpected_error>;
 pub(super) type SeekBefore = MetaExpr<kw::seek_before>;
+pub(super) type SetEndian = MetaExpr<kw::set_endian>;
+pub(super) type Size = MetaExpr<kw::size>;
+pub(super) type Stride = MetaExpr<kw::stride>;
 pub(super) type Stream = MetaIdent<kw::stream>;
 pub(super) type Temp = MetaVoid<kw::temp>;
 pub(super) type Try = MetaVoid<Token![try]>;
@@ -1,10 +1,19 @@
 use super::{
     attr_struct,
     top_level_attrs::StructAttr,
-    types::{Assert, CondEndian, Condition, ErrContext, FieldMode, Magic, Map, PassedArgs},
+    types::{
+        Assert, AssertionError, CondEndian, Condition, ErrContext, FieldMode, Magic, Map,
+        PassedArgs,
+    },
     FromAttrs, FromField, FromInput, ParseResult, SpannedValue, Struct, TrySet,
 };
-use crate::{binrw::Options, combine_error};
+use crate::{
+    binrw::{
+        codegen::sanitization::{STRIDE, WRITE_STRIDE},
+        Options,
+    },
+    combine_error,
+};
 use proc_macro2::TokenStream;
 use syn::spanned::Spanned;
 
@@ -26,22 +35,34 @@ attr_struct! {
         pub(crate) magic: Magic,
         #[from(RW:Args, RW:ArgsRaw)]
         pub(crate) args: PassedArgs,
-        #[from(RW:Calc, RW:TryCalc, RO:Default, RW:Ignore, RO:ParseWith, WO:WriteWith)]
+        #[from(RW:Calc, RW:TryCalc, RO:Default, RW:Ignore, RO:ParseWith, RO:Rest, WO:WriteWith)]
         pub(crate) field_mode: FieldMode,
-        #[from(RO:Count)]
+        #[from(RW:Count)]
         pub(crate) count: Option<TokenStream>,
-        #[from(RO:Offset)]
+        #[from(RW:Stride)]
+        pub(crate) stride: Option<TokenStream>,
+        #[from(RW:Offset)]
         pub(crate) offset: Option<TokenStream>,
+        #[from(RW:OffsetFromStart)]
+        pub(crate) offset_from_start: Option<()>,
+        #[from(RW:OffsetMap)]
+        pub(crate) offset_map: Option<TokenStream>,
         #[from(RW:If)]
         pub(crate) if_cond: Option<Condition>,
+        #[from(RO:IfNotEof)]
+        pub(crate) if_not_eof: Option<()>,
         #[from(RW:RestorePosition)]
         pub(crate) restore_position: Option<()>,
+        #[from(WO:Deferred)]
+        pub(crate) deferred: Option<SpannedValue<()>>,
         #[from(RO:Try)]
         pub(crate) do_try: Option<SpannedValue<()>>,
         #[from(RO:Temp)]
         pub(crate) temp: Option<()>,
         #[from(RW:Assert)]
         pub(crate) assertions: Vec<Assert>,
+        #[from(RO:Warn)]
+        pub(crate) warnings: Vec<Assert>,
         #[from(RO:ErrContext)]
         pub(crate) err_context: Option<ErrContext>,
         #[from(RW:PadBefore)]
@@ -56,6 +77,12 @@ attr_struct! {
         pub(crate) seek_before: Option<TokenStream>,
         #[from(RW:PadSizeTo)]
         pub(crate) pad_size_to: Option<TokenStream>,
+        #[from(RO:Size)]
+        pub(crate) size: Option<TokenStream>,
+        #[from(WO:Fill)]
+        pub(crate) fill: Option<TokenStream>,
+        #[from(RW:SetEndian)]
+        pub(crate) set_endian: Option<TokenStream>,
         #[from(RO:Debug)] // TODO is this really RO?
         pub(crate) debug: Option<()>,
     }
@@ -67,7 +94,7 @@ impl StructField {
     pub(crate) fn generated_value(&self) -> bool {
         matches!(
             self.field_mode,
-            FieldMode::TryCalc(_) | FieldMode::Calc(_) | FieldMode::Default
+            FieldMode::TryCalc(_) | FieldMode::Calc(_) | FieldMode::Default(_)
         )
     }
 
@@ -80,12 +107,12 @@ impl StructField {
 
     /// Returns true if the field is actually written.
     pub(crate) fn is_written(&self) -> bool {
-        !matches!(self.field_mode, FieldMode::Default)
+        !matches!(self.field_mode, FieldMode::Default(_))
     }
 
     /// Returns true if the field requires arguments.
     pub(crate) fn needs_args(&self) -> bool {
-        self.args.is_some() || self.count.is_some() || self.offset.is_some()
+        self.args.is_some() || self.has_named_arg_directives()
     }
 
     /// Returns true if the field overrides endianness.
@@ -96,7 +123,10 @@ impl StructField {
     /// Returns true if the field is using shorthand directives that are
     /// converted into named arguments.
     pub(crate) fn has_named_arg_directives(&self) -> bool {
-        self.count.is_some() || self.offset.is_some()
+        self.count.is_some()
+            || self.offset.is_some()
+            || self.offset_from_start.is_some()
+            || self.offset_map.is_some()
     }
 
     /// Returns true if the only field-level attributes are asserts
@@ -115,11 +145,17 @@ impl StructField {
             && matches!(self.map, Map::None)
             && matches!(self.args, PassedArgs::None)
             && matches!(self.field_mode, FieldMode::Normal)
+            && self.warnings.is_empty()
             && all_fields_none!(
                 count,
+                stride,
                 offset,
+                offset_from_start,
+                offset_map,
                 if_cond,
+                if_not_eof,
                 restore_position,
+                deferred,
                 do_try,
                 temp,
                 pad_before,
@@ -128,10 +164,23 @@ impl StructField {
                 align_after,
                 seek_before,
                 pad_size_to,
+                size,
+                fill,
+                set_endian,
                 magic
             )
     }
 
+    /// Converts a `stride` directive into the equivalent parser or writer
+    /// function.
+    fn resolve_stride(mut self, options: Options) -> Self {
+        if let (Some(stride), FieldMode::Normal) = (&self.stride, &self.field_mode) {
+            let function = if options.write { WRITE_STRIDE } else { STRIDE };
+            self.field_mode = FieldMode::Function(quote::quote! { #function(#stride) });
+        }
+        self
+    }
+
     /// Forces the field to be treated as a temporary variable even if it was
     /// not explicitly specified by a directive.
     ///
@@ -142,7 +191,7 @@ impl StructField {
         self.temp = Some(());
     }
 
-    fn validate(&self, _: Options) -> syn::Result<()> {
+    fn validate(&self, options: Options) -> syn::Result<()> {
         let mut all_errors = None::<syn::Error>;
 
         if self.do_try.is_some() && self.generated_value() {
@@ -170,7 +219,61 @@ impl StructField {
             );
         }
 
-        if self.has_named_arg_directives()
+        if let Some(stride) = &self.stride {
+            if !matches!(self.field_mode, FieldMode::Normal) {
+                combine_error(
+                    &mut all_errors,
+                    syn::Error::new(
+                        stride.span(),
+                        "`stride` is incompatible with `calc`, `default`, `ignore`, `parse_with`, `rest`, and `write_with`",
+                    ),
+                );
+            }
+        }
+
+        if let Some(deferred) = &self.deferred {
+            if !matches!(self.field_mode, FieldMode::Normal)
+                || self.map.is_some()
+                || self.map_stream.is_some()
+                || self.stride.is_some()
+                || self.offset_map.is_some()
+                || self
+                    .if_cond
+                    .as_ref()
+                    .map_or(false, |cond| cond.alternate.is_some())
+            {
+                combine_error(
+                    &mut all_errors,
+                    syn::Error::new(
+                        deferred.span(),
+                        "`deferred` is incompatible with `calc`, `try_calc`, `ignore`, `write_with`, `map`, `try_map`, `repr`, `map_stream`, `stride`, `offset_map`, and `if` with an alternate value",
+                    ),
+                );
+            }
+        }
+
+        for warning in &self.warnings {
+            if let AssertionError::Error(error) = &warning.consequent {
+                combine_error(
+                    &mut all_errors,
+                    syn::Error::new(
+                        error.span(),
+                        "`warn` message must be a format string; error objects can only be used with `assert`",
+                    ),
+                );
+            }
+        }
+
+        // When writing, `count` is checked against the value instead of being
+        // passed as an argument
+        let named_arg_directives = [
+            (!options.write && self.count.is_some(), "count"),
+            (self.offset.is_some(), "offset"),
+            (self.offset_from_start.is_some(), "offset_from_start"),
+            (self.offset_map.is_some(), "offset_map"),
+        ];
+
+        if named_arg_directives.iter().any(|(used, _)| *used)
             && !matches!(self.args, PassedArgs::None | PassedArgs::Named(..))
         {
             let (span, repr) = match &self.args {
@@ -186,10 +289,7 @@ impl StructField {
                 PassedArgs::Tuple(raw) => (raw.span(), raw.to_string()),
             };
 
-            for (used, name) in [
-                (self.count.is_some(), "count"),
-                (self.offset.is_some(), "offset"),
-            ] {
+            for (used, name) in named_arg_directives {
                 if used {
                     combine_error(&mut all_errors, syn::Error::new(
                         span,
@@ -226,18 +326,27 @@ impl FromField for StructField {
             args: <_>::default(),
             field_mode: <_>::default(),
             count: <_>::default(),
+            stride: <_>::default(),
             offset: <_>::default(),
+            offset_from_start: <_>::default(),
+            offset_map: <_>::default(),
             if_cond: <_>::default(),
+            if_not_eof: <_>::default(),
             restore_position: <_>::default(),
+            deferred: <_>::default(),
             do_try: <_>::default(),
             temp: <_>::default(),
             assertions: <_>::default(),
+            warnings: <_>::default(),
             pad_before: <_>::default(),
             pad_after: <_>::default(),
             align_before: <_>::default(),
             align_after: <_>::default(),
             seek_before: <_>::default(),
             pad_size_to: <_>::default(),
+            size: <_>::default(),
+            fill: <_>::default(),
+            set_endian: <_>::default(),
             #[cfg(feature = "verbose-backtrace")]
             keyword_spans: <_>::default(),
             err_context: <_>::default(),
@@ -253,16 +362,16 @@ impl FromField for StructField {
         match result {
             ParseResult::Ok(this) => {
                 if let Err(error) = this.validate(options) {
-                    ParseResult::Partial(this, error)
+                    ParseResult::Partial(this.resolve_stride(options), error)
                 } else {
-                    ParseResult::Ok(this)
+                    ParseResult::Ok(this.resolve_stride(options))
                 }
             }
             ParseResult::Partial(this, mut parse_error) => {
                 if let Err(error) = this.validate(options) {
                     parse_error.combine(error);
                 }
-                ParseResult::Partial(this, parse_error)
+                ParseResult::Partial(this.resolve_stride(options), parse_error)
             }
             ParseResult::Err(error) => ParseResult::Err(error),
         }
@@ -342,6 +451,20 @@ impl EnumVariant {
             Self::Unit(_) => true,
         }
     }
+
+    pub(crate) fn magic(&self) -> &Magic {
+        match self {
+            EnumVariant::Variant { options, .. } => &options.magic,
+            EnumVariant::Unit(field) => &field.magic,
+        }
+    }
+
+    pub(crate) fn magic_mut(&mut self) -> &mut Magic {
+        match self {
+            EnumVariant::Variant { options, .. } => &mut options.magic,
+            EnumVariant::Unit(field) => &mut field.magic,
+        }
+    }
 }
 
 impl From<EnumVariant> for Struct {
@@ -22,7 +22,10 @@ define_keywords! {
     count,
     dbg,
     default,
+    deferred,
     err_context,
+    fill,
+    if_not_eof,
     ignore,
     import,
     import_raw,
@@ -33,18 +36,26 @@ define_keywords! {
     map,
     map_stream,
     offset,
+    offset_from_start,
+    offset_map,
     pad_after,
     pad_before,
     pad_size_to,
     parse_with,
     pre_assert,
+    recover,
     repr,
+    rest,
     restore_position,
     return_all_errors,
     return_unexpected_error,
     seek_before,
+    set_endian,
+    size,
+    stride,
     stream,
     temp,
+    warn,
     try_calc,
     try_map,
     write_with,
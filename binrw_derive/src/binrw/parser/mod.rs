@@ -340,6 +340,30 @@ mod tests {
         struct Foo;
     });
 
+    try_error!(invalid_magic_alternatives_type: "must have the same type" {
+        #[br(magic = b"RIFF" | 0u32)]
+        struct Foo;
+    });
+
+    try_error!(invalid_magic_binding_enum: "`magic` bindings can only be used" {
+        #[br(magic = kind @ (0u8 | 1u8))]
+        enum Foo {
+            A(u8),
+        }
+    });
+
+    try_error!(invalid_magic_binding_unit_enum: "`magic` bindings can only be used" {
+        enum Foo {
+            #[br(magic = kind @ (0u8 | 1u8))]
+            A,
+        }
+    });
+
+    try_error!(invalid_magic_binding_unit_struct: "`magic` bindings can only be used" {
+        #[br(magic = kind @ (0u8 | 1u8))]
+        struct Foo;
+    });
+
     try_error!(try_calc_conflict: "`try` is incompatible" {
         struct Foo {
             #[br(try, calc(None))]
@@ -373,6 +397,16 @@ mod tests {
         assert_eq!(error.into_iter().count(), 3);
     }
 
+    try_error!(pad_size_to_map_conflict: "`pad_size_to` cannot be used together with" {
+        #[br(map = |x: u8| Foo(x), pad_size_to = 4)]
+        struct Foo(u8);
+    });
+
+    try_error!(recover_map_conflict: "`recover` cannot be used together with" {
+        #[br(map = |x: u8| Foo(x), recover)]
+        struct Foo(u8);
+    });
+
     try_error!(repr_magic_conflict: "mutually exclusive" {
         #[br(repr = u8)]
         enum Foo {
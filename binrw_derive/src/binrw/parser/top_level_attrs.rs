@@ -1,6 +1,6 @@
 use super::{
     attr_struct,
-    types::{Assert, CondEndian, EnumErrorMode, Imports, Magic, Map},
+    types::{Assert, CondEndian, EnumErrorMode, Imports, Magic, Map, SpannedValue},
     EnumVariant, FromInput, ParseResult, StructField, TrySet, UnitEnumField,
 };
 use crate::binrw::Options;
@@ -151,6 +151,14 @@ impl Input {
         }
     }
 
+    pub(crate) fn parse_with(&self) -> Option<&TokenStream> {
+        match self {
+            Input::Struct(s) | Input::UnitStruct(s) => s.parse_with.as_ref(),
+            Input::Enum(e) => e.parse_with.as_ref(),
+            Input::UnitOnlyEnum(e) => e.parse_with.as_ref(),
+        }
+    }
+
     pub(crate) fn magic(&self) -> &Magic {
         match self {
             Input::Struct(s) | Input::UnitStruct(s) => &s.magic,
@@ -209,6 +217,8 @@ attr_struct! {
         pub(crate) map: Map,
         #[from(RW:MapStream)]
         pub(crate) map_stream: Option<TokenStream>,
+        #[from(RO:ParseWith)]
+        pub(crate) parse_with: Option<TokenStream>,
         #[from(RW:Magic)]
         pub(crate) magic: Magic,
         #[from(RW:Import, RW:ImportRaw)]
@@ -217,6 +227,12 @@ attr_struct! {
         pub(crate) assertions: Vec<Assert>,
         #[from(RO:PreAssert)]
         pub(crate) pre_assertions: Vec<Assert>,
+        #[from(RW:PadSizeTo)]
+        pub(crate) pad_size_to: Option<TokenStream>,
+        #[from(RO:Recover)]
+        pub(crate) recover: Option<SpannedValue<()>>,
+        #[from(WO:Fill)]
+        pub(crate) fill: Option<TokenStream>,
         pub(crate) fields: Vec<StructField>,
         pub(crate) for_write: bool,
     }
@@ -242,7 +258,11 @@ impl Struct {
     pub(crate) fn has_no_attrs(&self) -> bool {
         matches!(self.endian, CondEndian::Inherited)
             && matches!(self.map, Map::None)
+            && self.parse_with.is_none()
             && self.magic.is_none()
+            && self.pad_size_to.is_none()
+            && self.recover.is_none()
+            && self.fill.is_none()
             && matches!(self.imports, Imports::None)
             && self.fields.iter().all(StructField::has_no_attrs)
     }
@@ -275,7 +295,31 @@ impl<const WRITE: bool> FromInput<StructAttr<WRITE>> for Struct {
     }
 
     fn validate(&self, options: Options) -> syn::Result<()> {
-        if self.map.is_none() && !options.derive {
+        if self.fields.is_empty() {
+            check_no_magic_binding(&self.magic)?;
+        }
+
+        check_parse_with_map(self.parse_with.as_ref(), &self.map)?;
+
+        if let Some(pad_size_to) = &self.pad_size_to {
+            if self.map.is_some() || self.parse_with.is_some() || self.map_stream.is_some() {
+                return Err(syn::Error::new(
+                    pad_size_to.span(),
+                    "`pad_size_to` cannot be used together with `map`, `parse_with`, or `map_stream`",
+                ));
+            }
+        }
+
+        if let Some(recover) = &self.recover {
+            if self.map.is_some() || self.parse_with.is_some() {
+                return Err(syn::Error::new(
+                    recover.span(),
+                    "`recover` cannot be used together with `map` or `parse_with`",
+                ));
+            }
+        }
+
+        if self.map.is_none() && self.parse_with.is_none() && !options.derive {
             return Ok(());
         }
 
@@ -287,6 +331,13 @@ impl<const WRITE: bool> FromInput<StructAttr<WRITE>> for Struct {
                 ));
             }
 
+            if self.parse_with.is_some() && !field.has_no_attrs() {
+                return Err(syn::Error::new(
+                    field.field.span(),
+                    "cannot use attributes on fields inside a struct with a struct-level `parse_with`",
+                ));
+            }
+
             if options.derive && field.is_temp(options.write) {
                 return Err(syn::Error::new(
                     field.field.span(),
@@ -316,6 +367,8 @@ attr_struct! {
         pub(crate) map: Map,
         #[from(RW:MapStream)]
         pub(crate) map_stream: Option<TokenStream>,
+        #[from(RO:ParseWith)]
+        pub(crate) parse_with: Option<TokenStream>,
         #[from(RW:Magic)]
         pub(crate) magic: Magic,
         #[from(RW:Import, RW:ImportRaw)]
@@ -344,6 +397,8 @@ impl<const WRITE: bool> FromInput<EnumAttr<WRITE>> for Enum {
     }
 
     fn validate(&self, _: Options) -> syn::Result<()> {
+        check_parse_with_map(self.parse_with.as_ref(), &self.map)?;
+
         if self.map.is_some() {
             if let Some(variant) = self.variants.iter().find(|variant| !variant.has_no_attrs()) {
                 return Err(syn::Error::new(
@@ -352,6 +407,32 @@ impl<const WRITE: bool> FromInput<EnumAttr<WRITE>> for Enum {
                 ));
             }
         }
+
+        if let Some(variant) = self.variants.iter().find(|variant| {
+            matches!(variant, EnumVariant::Variant { options, .. } if options.parse_with.is_some())
+        }) {
+            return Err(syn::Error::new(
+                variant.ident().span(),
+                "`parse_with` cannot be used on enum variants",
+            ));
+        }
+
+        if self.parse_with.is_some() {
+            if let Some(variant) = self.variants.iter().find(|variant| !variant.has_no_attrs()) {
+                return Err(syn::Error::new(
+                    variant.ident().span(),
+                    "cannot use attributes on variants inside an enum with an enum-level `parse_with`",
+                ));
+            }
+        }
+
+        check_no_magic_binding(&self.magic)?;
+        for variant in &self.variants {
+            if let EnumVariant::Unit(variant) = variant {
+                check_no_magic_binding(&variant.magic)?;
+            }
+        }
+
         Ok(())
     }
 }
@@ -368,6 +449,8 @@ attr_struct! {
         pub(crate) map: Map,
         #[from(RW:MapStream)]
         pub(crate) map_stream: Option<TokenStream>,
+        #[from(RO:ParseWith)]
+        pub(crate) parse_with: Option<TokenStream>,
         #[from(RW:Magic)]
         pub(crate) magic: Magic,
         #[from(RW:Import, RW:ImportRaw)]
@@ -402,7 +485,13 @@ impl<const WRITE: bool> FromInput<UnitEnumAttr<WRITE>> for UnitOnlyEnum {
     }
 
     fn validate(&self, options: Options) -> syn::Result<()> {
-        if self.map.as_repr().is_some() || self.is_magic_enum() {
+        check_parse_with_map(self.parse_with.as_ref(), &self.map)?;
+        check_no_magic_binding(&self.magic)?;
+        for field in &self.fields {
+            check_no_magic_binding(&field.magic)?;
+        }
+
+        if self.map.as_repr().is_some() || self.is_magic_enum() || self.parse_with.is_some() {
             Ok(())
         } else if options.write {
             Err(syn::Error::new(proc_macro2::Span::call_site(), "BinWrite on unit-like enums requires either `#[bw(repr = ...)]` on the enum or `#[bw(magic = ...)]` on at least one variant"))
@@ -411,3 +500,23 @@ impl<const WRITE: bool> FromInput<UnitEnumAttr<WRITE>> for UnitOnlyEnum {
         }
     }
 }
+
+fn check_parse_with_map(parse_with: Option<&TokenStream>, map: &Map) -> syn::Result<()> {
+    match (parse_with, map) {
+        (Some(parse_with), Map::Map(_) | Map::Try(_) | Map::Repr(_)) => Err(syn::Error::new(
+            parse_with.span(),
+            "`parse_with` cannot be used together with `map`, `try_map`, or `repr`",
+        )),
+        _ => Ok(()),
+    }
+}
+
+fn check_no_magic_binding(magic: &Magic) -> syn::Result<()> {
+    match magic.as_ref().and_then(|magic| magic.binding()) {
+        Some(binding) => Err(syn::Error::new(
+            binding.span(),
+            "`magic` bindings can only be used on structs and enum variants with fields",
+        )),
+        None => Ok(()),
+    }
+}
@@ -1,5 +1,8 @@
 use crate::{
-    binrw::parser::{attrs, TrySet},
+    binrw::{
+        codegen::sanitization::READ_TO_END,
+        parser::{attrs, TrySet},
+    },
     meta_types::KeywordToken,
 };
 use proc_macro2::TokenStream;
@@ -8,7 +11,7 @@ use quote::ToTokens;
 #[derive(Clone, Debug)]
 pub(crate) enum FieldMode {
     Normal,
-    Default,
+    Default(Option<TokenStream>),
     Calc(TokenStream),
     TryCalc(TokenStream),
     Function(TokenStream),
@@ -22,13 +25,13 @@ impl Default for FieldMode {
 
 impl From<attrs::Ignore> for FieldMode {
     fn from(_: attrs::Ignore) -> Self {
-        Self::Default
+        Self::Default(None)
     }
 }
 
 impl From<attrs::Default> for FieldMode {
-    fn from(_: attrs::Default) -> Self {
-        Self::Default
+    fn from(default: attrs::Default) -> Self {
+        Self::Default(default.value.map(ToTokens::into_token_stream))
     }
 }
 
@@ -50,6 +53,12 @@ impl From<attrs::ParseWith> for FieldMode {
     }
 }
 
+impl From<attrs::Rest> for FieldMode {
+    fn from(_: attrs::Rest) -> Self {
+        Self::Function(READ_TO_END.into_token_stream())
+    }
+}
+
 impl From<attrs::WriteWith> for FieldMode {
     fn from(write_with: attrs::WriteWith) -> Self {
         Self::Function(write_with.into_token_stream())
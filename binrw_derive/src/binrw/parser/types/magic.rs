@@ -2,7 +2,7 @@ use super::SpannedValue;
 use crate::{binrw::parser::attrs, meta_types::KeywordToken};
 use proc_macro2::TokenStream;
 use quote::{quote, ToTokens};
-use syn::Lit;
+use syn::{Ident, Lit};
 
 #[derive(PartialEq, Eq, Hash, Clone, Debug, PartialOrd, Ord)]
 pub(crate) enum Kind {
@@ -24,37 +24,79 @@ impl From<&Kind> for TokenStream {
 pub(crate) type Magic = Option<SpannedValue<Inner>>;
 
 #[derive(Clone, Debug)]
-pub(crate) struct Inner(Kind, TokenStream);
+pub(crate) struct Inner {
+    kind: Kind,
+    values: Vec<TokenStream>,
+    binding: Option<Ident>,
+}
 
 impl Inner {
     pub(crate) fn add_ref(&self) -> TokenStream {
-        match &self.0 {
+        match &self.kind {
             Kind::ByteStr(_) => quote! { & },
             Kind::Numeric(_) => TokenStream::new(),
         }
     }
 
+    pub(crate) fn binding(&self) -> Option<&Ident> {
+        self.binding.as_ref()
+    }
+
     pub(crate) fn deref_value(&self) -> TokenStream {
-        match self.0 {
-            Kind::ByteStr(_) => {
-                let value = &self.1;
-                quote! { *#value }
-            }
-            Kind::Numeric(_) => self.1.clone(),
-        }
+        deref_value(&self.kind, &self.values[0])
+    }
+
+    pub(crate) fn deref_values(&self) -> impl Iterator<Item = TokenStream> + '_ {
+        self.values
+            .iter()
+            .map(|value| deref_value(&self.kind, value))
+    }
+
+    /// Returns the values in a canonical form which can be compared for
+    /// equality regardless of how each literal was spelled, or `None` if any
+    /// value cannot be compared this way.
+    pub(crate) fn canonical_values(&self) -> Option<Vec<String>> {
+        self.values
+            .iter()
+            .map(|value| match syn::parse2::<Lit>(value.clone()).ok()? {
+                Lit::Int(i) => Some(i.base10_digits().to_owned()),
+                Lit::Byte(b) => Some(b.value().to_string()),
+                Lit::ByteStr(bytes) => Some(format!("{:?}", bytes.value())),
+                _ => None,
+            })
+            .collect()
+    }
+
+    pub(crate) fn is_single_value(&self) -> bool {
+        self.values.len() == 1 && self.binding.is_none()
     }
 
     pub(crate) fn kind(&self) -> &Kind {
-        &self.0
+        &self.kind
     }
 
-    pub(crate) fn match_value(&self) -> &TokenStream {
-        &self.1
+    pub(crate) fn match_pattern(&self) -> TokenStream {
+        let values = &self.values;
+        quote! { #(#values)|* }
+    }
+
+    pub(crate) fn write_value(&self) -> TokenStream {
+        self.binding
+            .as_ref()
+            .map_or_else(|| self.values[0].clone(), ToTokens::to_token_stream)
     }
 
     #[cfg(feature = "verbose-backtrace")]
-    pub(crate) fn into_match_value(self) -> TokenStream {
-        self.1
+    pub(crate) fn into_match_pattern(self) -> TokenStream {
+        let values = self.values;
+        quote! { #(#values)|* }
+    }
+}
+
+fn deref_value(kind: &Kind, value: &TokenStream) -> TokenStream {
+    match kind {
+        Kind::ByteStr(_) => quote! { *#value },
+        Kind::Numeric(_) => value.clone(),
     }
 }
 
@@ -62,42 +104,64 @@ impl TryFrom<attrs::Magic> for SpannedValue<Inner> {
     type Error = syn::Error;
 
     fn try_from(magic: attrs::Magic) -> Result<Self, Self::Error> {
-        let value = &magic.value;
-
-        let kind = match &value {
-            Lit::ByteStr(bytes) => Kind::ByteStr(format!("[u8; {}]", bytes.value().len())),
-            Lit::Byte(_) => Kind::Numeric("u8".to_owned()),
-            Lit::Int(i) => {
-                if i.suffix().is_empty() {
-                    return Err(syn::Error::new(
-                        value.span(),
-                        format!("expected explicit type suffix for integer literal\ne.g {i}u64",),
-                    ));
-                }
-                Kind::Numeric(i.suffix().to_owned())
-            }
-            Lit::Float(f) => {
-                if f.suffix().is_empty() {
+        let mut kind = None;
+        let mut values = Vec::with_capacity(magic.value.lits.len());
+        for value in &magic.value.lits {
+            let value_kind = lit_kind(value)?;
+            match &kind {
+                None => kind = Some(value_kind),
+                Some(kind) if *kind != value_kind => {
                     return Err(syn::Error::new(
                         value.span(),
-                        format!(
-                            "expected explicit type suffix for float literal\nvalid values are {f}f32 or {f}f64",
-                        ),
-                    ));
+                        "all `magic` alternatives must have the same type",
+                    ))
                 }
-                Kind::Numeric(f.suffix().to_owned())
+                Some(_) => {}
             }
-            Lit::Char(_) | Lit::Str(_) | Lit::Bool(_) | Lit::Verbatim(_) => {
-                return Err(syn::Error::new(
-                    value.span(),
-                    "expected byte string, byte, float, or int",
-                ))
-            }
-        };
+            values.push(value.to_token_stream());
+        }
 
         Ok(Self::new(
-            Inner(kind, value.to_token_stream()),
+            Inner {
+                // `LitAlternatives` never parses an empty list
+                kind: kind.unwrap(),
+                values,
+                binding: magic.value.binding.clone(),
+            },
             magic.keyword_span(),
         ))
     }
 }
+
+fn lit_kind(value: &Lit) -> syn::Result<Kind> {
+    Ok(match value {
+        Lit::ByteStr(bytes) => Kind::ByteStr(format!("[u8; {}]", bytes.value().len())),
+        Lit::Byte(_) => Kind::Numeric("u8".to_owned()),
+        Lit::Int(i) => {
+            if i.suffix().is_empty() {
+                return Err(syn::Error::new(
+                    value.span(),
+                    format!("expected explicit type suffix for integer literal\ne.g {i}u64",),
+                ));
+            }
+            Kind::Numeric(i.suffix().to_owned())
+        }
+        Lit::Float(f) => {
+            if f.suffix().is_empty() {
+                return Err(syn::Error::new(
+                    value.span(),
+                    format!(
+                        "expected explicit type suffix for float literal\nvalid values are {f}f32 or {f}f64",
+                    ),
+                ));
+            }
+            Kind::Numeric(f.suffix().to_owned())
+        }
+        Lit::Char(_) | Lit::Str(_) | Lit::Bool(_) | Lit::Verbatim(_) => {
+            return Err(syn::Error::new(
+                value.span(),
+                "expected byte string, byte, float, or int",
+            ))
+        }
+    })
+}
@@ -46,12 +46,12 @@ pub(crate) type MetaType<Keyword> = MetaValue<Keyword, Type>;
 /// both are always allowed
 pub(crate) type MetaIdent<Keyword> = MetaValue<Keyword, Ident>;
 
-/// `MetaLit` represents a key/lit pair
+/// `MetaLitAlternatives` represents a key/alternatives pair
 /// Takes two forms:
-/// * ident(lit)
-/// * ident = lit
+/// * ident(alternatives)
+/// * ident = alternatives
 /// both are always allowed
-pub(crate) type MetaLit<Keyword> = MetaValue<Keyword, Lit>;
+pub(crate) type MetaLitAlternatives<Keyword> = MetaValue<Keyword, LitAlternatives>;
 
 #[derive(Debug, Clone)]
 pub(crate) struct MetaValue<Keyword, Value> {
@@ -126,6 +126,45 @@ impl<Keyword> From<MetaVoid<Keyword>> for () {
     fn from(_: MetaVoid<Keyword>) -> Self {}
 }
 
+/// `MetaMaybeExpr` represents a key with an optional expr
+/// Takes three forms:
+/// * ident
+/// * ident(expr)
+/// * ident = expr
+pub(crate) type MetaMaybeExpr<Keyword> = MetaMaybeValue<Keyword, Expr>;
+
+#[derive(Debug, Clone)]
+pub(crate) struct MetaMaybeValue<Keyword, Value> {
+    pub(crate) ident: Keyword,
+    pub(crate) value: Option<Value>,
+}
+
+impl<Keyword: Parse, Value: Parse> Parse for MetaMaybeValue<Keyword, Value> {
+    fn parse(input: ParseStream<'_>) -> syn::Result<Self> {
+        let ident = input.parse()?;
+        let value = if input.peek(token::Paren) {
+            let content;
+            parenthesized!(content in input);
+            Some(content.parse()?)
+        } else if input.peek(Token![=]) {
+            input.parse::<Token![=]>()?;
+            Some(input.parse()?)
+        } else {
+            None
+        };
+
+        Ok(MetaMaybeValue { ident, value })
+    }
+}
+
+impl<Keyword: Token + Spanned, Value> KeywordToken for MetaMaybeValue<Keyword, Value> {
+    type Token = Keyword;
+
+    fn keyword_span(&self) -> Span {
+        self.ident.span()
+    }
+}
+
 #[derive(Debug, Clone)]
 pub(crate) struct MetaList<Keyword, ItemType> {
     pub(crate) ident: Keyword,
@@ -207,6 +246,38 @@ impl<Keyword: Token + Spanned, ParenItemType, BraceItemType> KeywordToken
     }
 }
 
+// This is like a `syn::Pat` except:
+// (1) Only allows literals, optionally separated by `|`;
+// (2) Only allows an ident binding (`ident @`) at the top level;
+// (3) The alternatives may be wrapped in parentheses.
+#[derive(Debug, Clone)]
+pub(crate) struct LitAlternatives {
+    pub(crate) binding: Option<Ident>,
+    pub(crate) lits: Punctuated<Lit, Token![|]>,
+}
+
+impl Parse for LitAlternatives {
+    fn parse(input: ParseStream<'_>) -> syn::Result<Self> {
+        let binding = if input.peek(Ident) && input.peek2(Token![@]) {
+            let binding = input.parse()?;
+            input.parse::<Token![@]>()?;
+            Some(binding)
+        } else {
+            None
+        };
+
+        let lits = if input.peek(token::Paren) {
+            let content;
+            parenthesized!(content in input);
+            Punctuated::parse_separated_nonempty(&content)?
+        } else {
+            Punctuated::parse_separated_nonempty(input)?
+        };
+
+        Ok(Self { binding, lits })
+    }
+}
+
 // This is like `syn::PatType` except:
 // (1) Implements `Parse`;
 // (2) No attributes;
@@ -281,6 +352,7 @@ mod tests {
     }
 
     type MetaValueTest = MetaValue<kw::test, Lit>;
+    type MetaMaybeValueTest = MetaMaybeValue<kw::test, Lit>;
     type MetaListTest = MetaList<kw::test_list, Lit>;
     type MetaAttrListTest = MetaAttrList<Lit>;
     type MetaEnclosedListTest = MetaEnclosedList<kw::test_enclosed_list, Lit, Lit>;
@@ -363,6 +435,16 @@ mod tests {
         );
     }
 
+    try_parse!(meta_maybe_value_void, MetaMaybeValueTest, { test });
+    try_parse!(meta_maybe_value_assign, MetaMaybeValueTest, { test = 3u8 });
+    try_parse!(meta_maybe_value_paren, MetaMaybeValueTest, { test(3u8) });
+    try_parse_fail!(
+        meta_maybe_value_wrong_value_type,
+        "expected literal",
+        MetaMaybeValueTest,
+        { test = u8 }
+    );
+
     try_parse!(meta_list, MetaListTest, { test_list(3u8, 3u8) });
     try_parse!(meta_list_empty, MetaListTest, { test_list() });
     try_parse_fail!(
@@ -454,6 +536,22 @@ mod tests {
     try_parse_fail!(ident_type_missing_colon, "expected `:`", IdentTypeMaybeDefault, { foo u8 });
     try_parse_fail!(ident_type_missing_ident, "expected identifier", IdentTypeMaybeDefault, { :u8 });
 
+    try_parse!(lit_alternatives_single, LitAlternatives, { b"RIFF" });
+    try_parse!(lit_alternatives_many, LitAlternatives, {
+        b"RIFF" | b"RIFX"
+    });
+    try_parse!(lit_alternatives_paren, LitAlternatives, {
+        (b"RIFF" | b"RIFX")
+    });
+    try_parse!(lit_alternatives_binding, LitAlternatives, { kind @ (b"RIFF" | b"RIFX") });
+    try_parse_fail!(lit_alternatives_missing_lit, "expected literal", LitAlternatives, { b"RIFF" | });
+    try_parse_fail!(
+        lit_alternatives_missing_binding,
+        "expected literal",
+        LitAlternatives,
+        { kind }
+    );
+
     try_parse!(meta_attr_list, MetaAttrListTest, { (1u8, 2u8, 3u8) });
     try_parse!(meta_attr_list_empty, MetaAttrListTest, { () });
     try_parse_fail!(